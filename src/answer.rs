@@ -0,0 +1,127 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+use crate::error::Error;
+
+/// A puzzle answer. Integers compare by value whatever their type, and are always written in
+/// plain decimal. Anything else compares by its written form, so text such as `"5"` still equals
+/// the integer 5.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// The sign and magnitude of an integer answer.
+    fn integer(&self) -> Option<(bool, u128)> {
+        match self {
+            Answer::Signed(n) => Some((*n < 0, n.unsigned_abs())),
+            Answer::Unsigned(n) => Some((false, *n)),
+            Answer::Text(_) => None,
+        }
+    }
+
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n),
+            Answer::Unsigned(n) => i128::try_from(*n).ok(),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.integer(), other.integer()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Reads an answer back from its written form, so integers written by [`Display`] become
+/// integers again and anything else stays text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = s.parse::<u128>() {
+            if n.to_string() == s {
+                return Ok(Answer::Unsigned(n));
+            }
+        }
+        if let Ok(n) = s.parse::<i128>() {
+            if n.to_string() == s {
+                return Ok(Answer::Signed(n));
+            }
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned, u8, u16, u32, u64, u128);
+answer_from!(Signed, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Only whole numbers convert, so a float answer is never written as `480.0` or `1e15`.
+impl TryFrom<f64> for Answer {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value.fract() != 0. || value.abs() >= 2f64.powi(127) {
+            return Err(Error::Answer(format!("{value} is not a whole number")));
+        }
+        Ok(match value < 0. {
+            true => Answer::Signed(value as i128),
+            false => Answer::Unsigned(value as u128),
+        })
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    toml::{self, Table, Value},
+};
+
+pub const DIR: &str = "answers";
+
+/// FNV-1a hash of an input, stable across platforms and compiler versions.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Recorded {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(Answer),
+    Missing,
+    Changed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Status::Missing => write!(f, "MISSING"),
+            Status::Changed => write!(f, "CHANGED (input differs from the recorded one)"),
+        }
+    }
+}
+
+/// Known-good answers for one year, stored in `answers/<year>.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub days: BTreeMap<u8, Recorded>,
+}
+
+fn to_value(answer: &Answer) -> Value {
+    match answer.as_i128() {
+        Some(n) => Value::Integer(n),
+        None => Value::String(answer.to_string()),
+    }
+}
+
+/// Reads an answer back as the kind [`to_value`] wrote, so text that happens to look like a number
+/// stays text.
+pub(crate) fn to_answer(value: &Value) -> Answer {
+    match value {
+        Value::Integer(n) if *n < 0 => Answer::Signed(*n),
+        Value::Integer(n) => Answer::Unsigned(*n as u128),
+        Value::String(s) => Answer::Text(s.clone()),
+        other => Answer::Text(other.to_string()),
+    }
+}
+
+impl Answers {
+    pub fn path(dir: impl AsRef<Path>, year: u16) -> PathBuf {
+        dir.as_ref().join(format!("{year}.toml"))
+    }
+
+    pub fn load(path: &Path) -> Result<Answers> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(error) => return Err(Error::io(path)(error)),
+        };
+        let mut answers = Answers::default();
+        for (key, value) in toml::parse(&contents)? {
+            let day: u8 = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or(Error::Parse(format!("unexpected table in answers: {key}")))?;
+            let table = value
+                .as_table()
+                .ok_or(Error::Parse(format!("{key} should be a table")))?;
+            let recorded = Recorded {
+                input: table
+                    .get("input")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                part1: table.get("part1").map(to_answer),
+                part2: table.get("part2").map(to_answer),
+            };
+            answers.days.insert(day, recorded);
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut root = Table::new();
+        for (day, recorded) in self.days.iter() {
+            let mut table = Table::new();
+            table.insert("input".to_string(), Value::String(recorded.input.clone()));
+            for (key, answer) in [("part1", &recorded.part1), ("part2", &recorded.part2)] {
+                if let Some(answer) = answer {
+                    table.insert(key.to_string(), to_value(answer));
+                }
+            }
+            root.insert(format!("day{:02}", day), Value::Table(table));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io(parent))?;
+        }
+        fs::write(path, toml::to_string(&root)).map_err(Error::io(path))
+    }
+
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &Answer) -> Status {
+        let Some(recorded) = self.days.get(&day) else {
+            return Status::Missing;
+        };
+        match recorded.part(part) {
+            None => Status::Missing,
+            Some(_) if recorded.input != input => Status::Changed,
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+        }
+    }
+
+    /// Stores an answer, forgetting the other part's answer if it was computed from a different input.
+    pub fn record(&mut self, day: u8, part: u8, input: &str, answer: &Answer) {
+        let recorded = self.days.entry(day).or_default();
+        if recorded.input != input {
+            *recorded = Recorded {
+                input: input.to_string(),
+                ..Recorded::default()
+            };
+        }
+        *recorded.part_mut(part) = Some(answer.clone());
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{Error, Result},
+    json::{self, Object, Value},
+    registry::Day,
+    solution::Params,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> Value {
+        let mut object = Object::new();
+        for (key, duration) in [
+            ("min", self.min),
+            ("median", self.median),
+            ("max", self.max),
+        ] {
+            object.insert(key.to_string(), Value::Integer(duration.as_nanos() as i128));
+        }
+        Value::Object(object)
+    }
+
+    fn from_json(value: &Value) -> Option<Stats> {
+        let object = value.as_object()?;
+        let nanos = |key| Some(Duration::from_nanos(object.get(key)?.as_i128()? as u64));
+        Some(Stats {
+            min: nanos("min")?,
+            median: nanos("median")?,
+            max: nanos("max")?,
+        })
+    }
+}
+
+/// Timings for the phases of one day: `parse`, then `part1` and/or `part2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub year: u16,
+    pub day: u8,
+    pub phases: Vec<(String, Stats)>,
+}
+
+/// Times `iterations` runs of `phase`, turning a panic into an error so one day cannot end the run.
+fn time<T>(iterations: usize, phase: &str, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        panic::catch_unwind(AssertUnwindSafe(&mut f))
+            .map_err(|_| Error::Panicked(phase.to_string()))??;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::new(samples))
+}
+
+/// Times parsing and each requested part separately. Unimplemented parts are skipped.
+pub fn measure(
+    entry: &Day,
+    parts: &[u8],
+    input: &str,
+    params: &Params,
+    iterations: usize,
+) -> Result<Timings> {
+    let iterations = iterations.max(1);
+    let mut phases = vec![(
+        "parse".to_string(),
+        time(iterations, "parse", || (entry.parse)(input, params))?,
+    )];
+    let parsed = (entry.parse)(input, params)?;
+    for &part in parts {
+        let phase = format!("part{part}");
+        match time(iterations, &phase, || parsed.part(part)) {
+            Ok(stats) => phases.push((phase, stats)),
+            Err(Error::Unimplemented) => (),
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(Timings {
+        year: entry.year,
+        day: entry.day,
+        phases,
+    })
+}
+
+/// Median timings saved by a previous run, keyed by year, day and phase, as in `2024/day14`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    pub days: Vec<Timings>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline> {
+        let contents = fs::read_to_string(path).map_err(Error::io(path))?;
+        let invalid = || Error::Parse(format!("{}: not a benchmark baseline", path.display()));
+        let root = json::parse(&contents)?;
+        let mut days = Vec::new();
+        for (key, value) in root.as_object().ok_or_else(invalid)? {
+            let (year, day) = key
+                .split_once("/day")
+                .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)))
+                .ok_or_else(invalid)?;
+            let phases = value
+                .as_object()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|(phase, stats)| Some((phase.clone(), Stats::from_json(stats)?)))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+            days.push(Timings { year, day, phases });
+        }
+        Ok(Baseline { days })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut root = Object::new();
+        for timings in self.days.iter() {
+            let phases = timings
+                .phases
+                .iter()
+                .map(|(phase, stats)| (phase.clone(), stats.to_json()))
+                .collect();
+            let key = format!("{}/day{:02}", timings.year, timings.day);
+            root.insert(key, Value::Object(phases));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io(parent))?;
+        }
+        fs::write(path, format!("{}\n", Value::Object(root))).map_err(Error::io(path))
+    }
+
+    pub fn get(&self, year: u16, day: u8, phase: &str) -> Option<Stats> {
+        let timings = self
+            .days
+            .iter()
+            .find(|timings| (timings.year, timings.day) == (year, day))?;
+        timings
+            .phases
+            .iter()
+            .find(|(name, _)| name == phase)
+            .map(|(_, stats)| *stats)
+    }
+}
+
+/// Relative change of the median against the baseline, in percent.
+pub fn change(current: Stats, baseline: Stats) -> f64 {
+    let baseline = baseline.median.as_secs_f64().max(f64::EPSILON);
+    (current.median.as_secs_f64() - baseline) / baseline * 100.0
+}
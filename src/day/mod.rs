@@ -1,11 +1,36 @@
 use aocd::*;
 
-#[aocd(2024, 1)]
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Model = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_model: &Self::Model) -> Result<Self::Answer1> {
+        Err(Error::Unimplemented)
+    }
+
+    fn part2(_model: &Self::Model) -> Result<Self::Answer2> {
+        Err(Error::Unimplemented)
+    }
+}
+
+#[aocd(YEAR, DAY)]
 pub fn solution1() {
-    
+    submit!(1, DayNN::solve1(&input!()).unwrap());
 }
 
-#[aocd(2024, 1)]
+#[aocd(YEAR, DAY)]
 pub fn solution2() {
-    
-}
\ No newline at end of file
+    submit!(2, DayNN::solve2(&input!()).unwrap());
+}
@@ -1,4 +0,0 @@
-pub mod solution1;
-pub mod solution2;
-pub use solution1::solution1;
-pub use solution2::solution2;
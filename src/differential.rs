@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use crate::{
+    answer::Answer,
+    error::Result,
+    registry,
+    solution::{self, Params, Parsed},
+    *,
+};
+
+/// Another implementation of some parts of a day, expected to agree with the registered one.
+pub struct Variant {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub parts: &'static [u8],
+    pub parse: fn(&str, &Params) -> Result<Box<dyn Parsed>>,
+}
+
+macro_rules! variant {
+    ($year:literal, $day:literal, $name:literal, $parts:expr, $solution:ty) => {
+        Variant {
+            year: $year,
+            day: $day,
+            name: $name,
+            parts: &$parts,
+            parse: solution::parse::<$solution>,
+        }
+    };
+}
+
+pub static VARIANTS: &[Variant] = &[
+    variant!(
+        2024,
+        11,
+        "without_cached",
+        [1, 2],
+        y2024::day11::without_cached::WithoutCached
+    ),
+    variant!(2024, 15, "widened", [1], y2024::day15::widened::Widened),
+];
+
+pub fn find(year: u16, day: u8) -> impl Iterator<Item = &'static Variant> {
+    VARIANTS
+        .iter()
+        .filter(move |variant| variant.year == year && variant.day == day)
+}
+
+/// A part on which a variant and the registered solution disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {:02} part {}: {} gave {}, expected {}",
+            self.year, self.day, self.part, self.variant, self.found, self.expected
+        )
+    }
+}
+
+fn answers(
+    parse: fn(&str, &Params) -> Result<Box<dyn Parsed>>,
+    input: &str,
+    params: &Params,
+    parts: &[u8],
+) -> Vec<std::result::Result<Answer, String>> {
+    match parse(input, params) {
+        Ok(parsed) => parts
+            .iter()
+            .map(|&part| parsed.part(part).map_err(|error| error.to_string()))
+            .collect(),
+        Err(error) => vec![Err(error.to_string()); parts.len()],
+    }
+}
+
+fn describe(answer: std::result::Result<Answer, String>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(error) => format!("error: {error}"),
+    }
+}
+
+/// Solves the input with both the variant and the registered solution, returning every part on
+/// which they disagree.
+pub fn compare(variant: &Variant, input: &str, params: &Params) -> Vec<Divergence> {
+    let entry = registry::find(variant.year, variant.day).unwrap();
+    let expected = answers(entry.parse, input, params, variant.parts);
+    let found = answers(variant.parse, input, params, variant.parts);
+    variant
+        .parts
+        .iter()
+        .zip(expected.into_iter().zip(found))
+        .filter(|(_, (expected, found))| expected != found)
+        .map(|(&part, (expected, found))| Divergence {
+            year: variant.year,
+            day: variant.day,
+            part,
+            variant: variant.name,
+            expected: describe(expected),
+            found: describe(found),
+        })
+        .collect()
+}
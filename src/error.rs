@@ -0,0 +1,136 @@
+use std::{fmt::Display, io, num::ParseIntError, path::Path, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(String),
+    Syntax(ParseError),
+    Input(String),
+    NoSolution,
+    Unimplemented,
+    Answer(String),
+    /// The named phase of a solution panicked.
+    Panicked(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(message) => write!(f, "parse error: {message}"),
+            Error::Syntax(error) => write!(f, "parse error: {error}"),
+            Error::Input(message) => write!(f, "input error: {message}"),
+            Error::NoSolution => write!(f, "no solution found"),
+            Error::Unimplemented => write!(f, "not implemented"),
+            Error::Answer(message) => write!(f, "invalid answer: {message}"),
+            Error::Panicked(phase) => write!(f, "panicked in {phase}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// See [`ParseError::within`]; other errors are returned unchanged.
+    pub fn within(self, outer: &str, fragment: &str) -> Error {
+        match self {
+            Error::Syntax(error) => Error::Syntax(error.within(outer, fragment)),
+            error => error,
+        }
+    }
+
+    pub fn on_day(self, day: u8) -> Error {
+        match self {
+            Error::Syntax(error) => Error::Syntax(ParseError {
+                day: Some(day),
+                ..error
+            }),
+            error => error,
+        }
+    }
+
+    pub fn io(path: impl AsRef<Path>) -> impl Fn(io::Error) -> Error {
+        let path = path.as_ref().display().to_string();
+        move |error| Error::Input(format!("{path}: {error}"))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: ParseIntError) -> Self {
+        Error::Parse(value.to_string())
+    }
+}
+
+/// Where and why an input could not be read. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+/// Byte offset of `inner` within `outer`, when the former is a slice of the latter.
+fn offset(outer: &str, inner: &str) -> usize {
+    let start = outer.as_ptr() as usize;
+    let position = inner.as_ptr() as usize;
+    if (start..=start + outer.len()).contains(&position) {
+        position - start
+    } else {
+        0
+    }
+}
+
+impl ParseError {
+    /// An error at `text`, a slice of `fragment`, positioned relative to the start of `fragment`.
+    pub fn new(fragment: &str, text: &str, expected: impl Into<String>) -> ParseError {
+        let before = &fragment[..offset(fragment, text)];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Repositions an error found in `fragment` relative to `outer`, which contains it.
+    pub fn within(self, outer: &str, fragment: &str) -> ParseError {
+        let start = ParseError::new(outer, fragment, "");
+        ParseError {
+            line: self.line + start.line - 1,
+            column: match self.line {
+                1 => self.column + start.column - 1,
+                _ => self.column,
+            },
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.text.as_str() {
+            "" => write!(f, "expected {}, found nothing", self.expected),
+            text => write!(f, "expected {}, found {:?}", self.expected, text),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Syntax(value)
+    }
+}
+
+/// Parses `text`, a slice of `fragment`, reporting its position when it is not a number.
+pub fn number<T: FromStr>(fragment: &str, text: &str) -> std::result::Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(fragment, text, "a number"))
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
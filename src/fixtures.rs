@@ -0,0 +1,89 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    answers::to_answer,
+    error::{Error, Result},
+    input::normalize,
+    registry::Day,
+    solution::Params,
+    toml::{self, Value},
+};
+
+pub const DIR: &str = "fixtures";
+
+/// An example input under `fixtures/<year>/dayNN/<name>.txt`, with its expected
+/// answers and parameters in the neighbouring `<name>.toml`.
+#[derive(Debug)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+pub fn day_dir(dir: impl AsRef<Path>, year: u16, day: u8) -> PathBuf {
+    dir.as_ref()
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Fixture> {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let input = fs::read_to_string(path.with_extension("txt")).map_err(Error::io(path))?;
+        let expected = fs::read_to_string(path).map_err(Error::io(path))?;
+        let expected = toml::parse(&expected)?;
+        let mut params = Params::default();
+        if let Some(table) = expected.get("params").and_then(Value::as_table) {
+            for (key, value) in table {
+                params.insert(key, value.to_string());
+            }
+        }
+        Ok(Fixture {
+            name,
+            input: normalize(&input),
+            params,
+            part1: expected.get("part1").map(to_answer),
+            part2: expected.get("part2").map(to_answer),
+        })
+    }
+
+    /// Runs the fixture through a day's solution, describing every mismatch.
+    pub fn check(&self, entry: &Day) -> Vec<String> {
+        let parsed = match (entry.parse)(&self.input, &self.params) {
+            Ok(parsed) => parsed,
+            Err(error) => return vec![format!("{}: {}", self.name, error)],
+        };
+        let mut failures = Vec::new();
+        for (part, expected) in [(1, &self.part1), (2, &self.part2)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            match parsed.part(part) {
+                Ok(answer) if answer == *expected => (),
+                Ok(answer) => failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    self.name, part, expected, answer
+                )),
+                Err(error) => failures.push(format!("{} part {}: {}", self.name, part, error)),
+            }
+        }
+        failures
+    }
+}
+
+pub fn load(dir: impl AsRef<Path>, year: u16, day: u8) -> Result<Vec<Fixture>> {
+    let dir = day_dir(dir, year, day);
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .map_err(Error::io(&dir))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| Fixture::load(path)).collect()
+}
@@ -0,0 +1,242 @@
+use std::{collections::HashSet, ops::Range};
+
+use crate::utils::{search, xy, Grid, XY};
+
+/// SplitMix64, so that a seed produces the same input on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        range.start + self.below(range.end.abs_diff(range.start)) as i64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// A maze with walls on every even row and column, `S` in the bottom left and `E` in the top
+/// right. Without loops there is exactly one path; `loops` is the share of walls knocked out.
+fn carve(rng: &mut Rng, width: usize, height: usize, loops: f64) -> Grid<char> {
+    let (width, height) = (width.max(5) | 1, height.max(5) | 1);
+    let mut grid = Grid::new(width, height, '#');
+    let start = xy(1, 1);
+    grid[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let mut steps = cell.neighbors().map(|next| next - cell);
+        rng.shuffle(&mut steps);
+        let step = steps
+            .into_iter()
+            .find(|&step| grid.get(cell + step * 2) == Some(&'#'));
+        match step {
+            Some(step) => {
+                grid[cell + step] = '.';
+                grid[cell + step * 2] = '.';
+                stack.push(cell + step * 2);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    let walls: Vec<XY> = grid
+        .positions()
+        .filter(|&pos| grid[pos] == '#' && (pos.x + pos.y) % 2 == 1)
+        .filter(|&pos| pos.x > 0 && pos.y > 0)
+        .filter(|&pos| pos.x < width as i32 - 1 && pos.y < height as i32 - 1)
+        .collect();
+    for wall in walls {
+        if rng.chance(loops) {
+            grid[wall] = '.';
+        }
+    }
+    grid[xy(1, height as i32 - 2)] = 'S';
+    grid[xy(width as i32 - 2, 1)] = 'E';
+    grid
+}
+
+/// A day 16 maze. Sizes are rounded up to odd numbers.
+pub fn maze(rng: &mut Rng, width: usize, height: usize, loops: f64) -> String {
+    carve(rng, width, height, loops).to_string()
+}
+
+/// A day 20 racetrack: a single corridor from `S` to `E`.
+pub fn racetrack(rng: &mut Rng, width: usize, height: usize) -> String {
+    let maze = carve(rng, width, height, 0.);
+    let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+    let neighbors = |&pos: &XY| maze.neighbors(pos).filter(|&next| maze[next] != '#');
+    let path = search::bfs(start, neighbors, |&pos| pos == end)
+        .path(&end)
+        .unwrap();
+    let mut track = Grid::new(maze.width(), maze.height(), '#');
+    for pos in path {
+        track[pos] = maze[pos];
+    }
+    track.to_string()
+}
+
+/// Day 14 robots on a `width` by `height` floor.
+pub fn robots(rng: &mut Rng, count: usize, width: usize, height: usize) -> String {
+    let (width, height) = (width as i64, height as i64);
+    (0..count)
+        .map(|_| {
+            let (x, y) = (rng.range(0..width), rng.range(0..height));
+            let (dx, dy) = (rng.range(-width..width), rng.range(-height..height));
+            format!("p={x},{y} v={dx},{dy}\n")
+        })
+        .collect()
+}
+
+/// A day 09 disk map of `files` files, each followed by a gap except the last.
+pub fn disk_map(rng: &mut Rng, files: usize) -> String {
+    let mut map = String::new();
+    for i in 0..files {
+        map.push(char::from(b'1' + rng.below(9) as u8));
+        if i + 1 < files {
+            map.push(char::from(b'0' + rng.below(10) as u8));
+        }
+    }
+    map.push('\n');
+    map
+}
+
+fn wire_name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..3)
+            .map(|_| char::from(b'a' + rng.below(23) as u8))
+            .collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A day 24 ripple carry adder over `bits` bit inputs, with random input values and wire names.
+pub fn netlist(rng: &mut Rng, bits: usize) -> String {
+    let mut taken = HashSet::new();
+    let mut gates = Vec::new();
+    let mut gate = |rng: &mut Rng, a: &str, op: &str, b: &str, out: &str| {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        gates.push(format!("{a} {op} {b} -> {out}"));
+    };
+    let mut carry = String::new();
+    for i in 0..bits {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        if i == 0 {
+            gate(rng, &x, "XOR", &y, &z);
+            carry = wire_name(rng, &mut taken);
+            gate(rng, &x, "AND", &y, &carry);
+            continue;
+        }
+        let [sum, both, through] = [(); 3].map(|_| wire_name(rng, &mut taken));
+        gate(rng, &x, "XOR", &y, &sum);
+        gate(rng, &sum, "XOR", &carry, &z);
+        gate(rng, &x, "AND", &y, &both);
+        gate(rng, &sum, "AND", &carry, &through);
+        carry = match i + 1 == bits {
+            true => format!("z{bits:02}"),
+            false => wire_name(rng, &mut taken),
+        };
+        gate(rng, &both, "OR", &through, &carry);
+    }
+    rng.shuffle(&mut gates);
+    let inputs: Vec<String> = ["x", "y"]
+        .iter()
+        .flat_map(|wire| (0..bits).map(move |i| format!("{wire}{i:02}")))
+        .map(|wire| format!("{wire}: {}", rng.below(2)))
+        .collect();
+    format!("{}\n\n{}\n", inputs.join("\n"), gates.join("\n"))
+}
+
+/// Day 19 towels: `patterns` distinct stripe patterns, and `designs` designs of about `length`
+/// stripes, half of them assembled from the patterns and half of them random.
+pub fn towels(rng: &mut Rng, patterns: usize, designs: usize, length: usize) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let stripes =
+        |rng: &mut Rng, n: usize| -> String { (0..n).map(|_| *rng.choose(&COLORS)).collect() };
+    let mut towels = HashSet::new();
+    let mut dictionary = Vec::new();
+    while dictionary.len() < patterns {
+        let length = 1 + rng.below(8) as usize;
+        let towel = stripes(rng, length);
+        if towels.insert(towel.clone()) {
+            dictionary.push(towel);
+        }
+    }
+    let designs: Vec<String> = (0..designs)
+        .map(|i| match i % 2 {
+            0 => {
+                let mut design = String::new();
+                while design.len() < length {
+                    let towel: &String = rng.choose(&dictionary);
+                    design += towel;
+                }
+                design
+            }
+            _ => stripes(rng, length),
+        })
+        .collect();
+    format!("{}\n\n{}\n", dictionary.join(", "), designs.join("\n"))
+}
+
+/// Day 11 stones with up to `digits` digits each.
+pub fn stones(rng: &mut Rng, count: usize, digits: u32) -> String {
+    let stones: Vec<String> = (0..count)
+        .map(|_| rng.below(10u64.pow(digits)).to_string())
+        .collect();
+    format!("{}\n", stones.join(" "))
+}
+
+/// A day 15 warehouse walled in on every side, followed by `moves` robot moves.
+pub fn warehouse(rng: &mut Rng, width: usize, height: usize, moves: usize) -> String {
+    let (width, height) = (width.max(3), height.max(3));
+    let mut grid = Grid::new(width, height, '#');
+    let inside: Vec<XY> = grid
+        .positions()
+        .filter(|pos| pos.x > 0 && pos.y > 0)
+        .filter(|pos| pos.x < width as i32 - 1 && pos.y < height as i32 - 1)
+        .collect();
+    for &pos in inside.iter() {
+        grid[pos] = *rng.choose(&['.', '.', '.', '.', '.', 'O', 'O', '#']);
+    }
+    grid[*rng.choose(&inside)] = '@';
+    let moves: String = (0..moves)
+        .map(|_| *rng.choose(&['^', '>', 'v', '<']))
+        .collect();
+    let lines: Vec<&str> = moves
+        .as_bytes()
+        .chunks(70)
+        .map(|line| std::str::from_utf8(line).unwrap())
+        .collect();
+    format!("{grid}\n{}\n", lines.join("\n"))
+}
@@ -0,0 +1,214 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    json::{self, Object, Value},
+    submit::Verdict,
+};
+
+pub const DIR: &str = "submissions";
+
+/// One answer sent to the site, and what it said.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+fn verdict_name(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::Wrong => "wrong",
+        Verdict::TooHigh => "too high",
+        Verdict::TooLow => "too low",
+        Verdict::RateLimited(_) => "rate limited",
+        Verdict::WrongLevel => "wrong level",
+    }
+}
+
+fn read_verdict(name: &str) -> Option<Verdict> {
+    Some(match name {
+        "correct" => Verdict::Correct,
+        "wrong" => Verdict::Wrong,
+        "too high" => Verdict::TooHigh,
+        "too low" => Verdict::TooLow,
+        "rate limited" => Verdict::RateLimited(None),
+        "wrong level" => Verdict::WrongLevel,
+        _ => return None,
+    })
+}
+
+impl Attempt {
+    pub fn new(day: u8, part: u8, answer: Answer, verdict: Verdict) -> Attempt {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Attempt {
+            day,
+            part,
+            answer,
+            verdict,
+            time,
+        }
+    }
+
+    /// Whether the site said the answer was wrong, rather than not judging it at all.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self.verdict,
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+
+    fn to_json(&self) -> Value {
+        let mut object = Object::new();
+        object.insert("day".to_string(), Value::Integer(self.day as i128));
+        object.insert("part".to_string(), Value::Integer(self.part as i128));
+        object.insert("answer".to_string(), Value::String(self.answer.to_string()));
+        let verdict = verdict_name(&self.verdict).to_string();
+        object.insert("verdict".to_string(), Value::String(verdict));
+        object.insert("time".to_string(), Value::Integer(self.time as i128));
+        Value::Object(object)
+    }
+
+    fn from_json(value: &Value) -> Option<Attempt> {
+        let object = value.as_object()?;
+        let text = |key: &str| match object.get(key)? {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        };
+        let number = |key: &str| object.get(key)?.as_i128();
+        Some(Attempt {
+            day: number("day")?.try_into().ok()?,
+            part: number("part")?.try_into().ok()?,
+            answer: text("answer")?.parse().unwrap(),
+            verdict: read_verdict(text("verdict")?)?,
+            time: number("time")?.try_into().ok()?,
+        })
+    }
+}
+
+/// Every answer submitted for one year, kept in `submissions/<year>.jsonl` one attempt per line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn path(dir: impl AsRef<Path>, year: u16) -> PathBuf {
+        dir.as_ref().join(format!("{year}.jsonl"))
+    }
+
+    pub fn load(path: &Path) -> Result<History> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(error) => return Err(Error::io(path)(error)),
+        };
+        let attempts = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Attempt::from_json(&json::parse(line)?).ok_or_else(|| {
+                    Error::Parse(format!("{}: not a submission: {line}", path.display()))
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(History { attempts })
+    }
+
+    /// Adds an attempt to the log at `path` as well as to this history.
+    pub fn append(&mut self, path: &Path, attempt: Attempt) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io(parent))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(Error::io(path))?;
+        writeln!(file, "{}", attempt.to_json()).map_err(Error::io(path))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// The highest answer that was too low and the lowest that was too high. The right answer lies
+    /// strictly between them.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let judged = |verdict: Verdict| {
+            self.attempts(day, part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.as_i128())
+        };
+        (
+            judged(Verdict::TooLow).max(),
+            judged(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Why `answer` is already known not to be accepted, if it is.
+    pub fn refusal(&self, day: u8, part: u8, answer: &Answer) -> Option<String> {
+        if let Some(correct) = self
+            .attempts(day, part)
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+        {
+            return Some(format!("already solved with {}", correct.answer));
+        }
+        if let Some(wrong) = self
+            .attempts(day, part)
+            .find(|attempt| attempt.is_wrong() && attempt.answer == *answer)
+        {
+            let verdict = verdict_name(&wrong.verdict);
+            return Some(format!("{answer} was already submitted and was {verdict}"));
+        }
+        let (low, high) = self.bounds(day, part);
+        match answer.as_i128() {
+            Some(n) if low.is_some_and(|low| n <= low) => Some(format!(
+                "{answer} is not above {}, which was too low",
+                low.unwrap()
+            )),
+            Some(n) if high.is_some_and(|high| n >= high) => Some(format!(
+                "{answer} is not below {}, which was too high",
+                high.unwrap()
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// `YYYY-MM-DD HH:MM:SS` in UTC.
+pub fn timestamp(time: u64) -> String {
+    let (days, seconds) = ((time / 86400) as i64, time % 86400);
+    // Howard Hinnant's days-to-civil conversion.
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
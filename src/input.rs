@@ -0,0 +1,92 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::{
+    error::{Error, Result},
+    submit::{self, Http, Submitter},
+};
+
+pub trait InputSource: Sync {
+    fn read(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Puzzle inputs stored as `<dir>/<year>/dayNN.txt`, optionally fetched when missing.
+pub struct Cache {
+    pub dir: PathBuf,
+    pub fetch: Option<Box<dyn Submitter>>,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, fetch: Option<Box<dyn Submitter>>) -> Cache {
+        Cache {
+            dir: dir.into(),
+            fetch,
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    fn download(&self, year: u16, day: u8, from: &dyn Submitter) -> Result<String> {
+        let input = from.input(year, day)?;
+        let path = self.path(year, day);
+        fs::create_dir_all(path.parent().unwrap()).map_err(Error::io(&path))?;
+        fs::write(&path, &input).map_err(Error::io(&path))?;
+        Ok(input)
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Cache::new("inputs", Some(Box::new(Http::new(submit::SITE))))
+    }
+}
+
+impl InputSource for Cache {
+    fn read(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(normalize(&input)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound && self.fetch.is_some() => {
+                let from = self.fetch.as_deref().unwrap();
+                Ok(normalize(&self.download(year, day, from)?))
+            }
+            Err(error) => Err(Error::io(&path)(error)),
+        }
+    }
+}
+
+pub struct File(pub PathBuf);
+
+impl InputSource for File {
+    fn read(&self, _: u16, _: u8) -> Result<String> {
+        let input = fs::read_to_string(&self.0).map_err(Error::io(&self.0))?;
+        Ok(normalize(&input))
+    }
+}
+
+pub struct Stdin;
+
+impl InputSource for Stdin {
+    fn read(&self, _: u16, _: u8) -> Result<String> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(Error::io("stdin"))?;
+        Ok(normalize(&input))
+    }
+}
+
+/// Converts line endings to `\n` and strips trailing newlines, matching what aocd hands out.
+pub fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
@@ -0,0 +1,224 @@
+use std::{collections::BTreeMap, fmt::Display, iter::Peekable, str::CharIndices};
+
+use crate::error::{Error, Result};
+
+/// A JSON document, as used for benchmark baselines and machine-readable output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Boolean(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Object),
+}
+
+pub type Object = BTreeMap<String, Value>;
+
+impl Value {
+    pub fn as_object(&self) -> Option<&Object> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Compact JSON, without any whitespace between tokens.
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Boolean(b) => write!(f, "{b}"),
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Float(x) if x.is_finite() => write!(f, "{x:?}"),
+            Value::Float(_) => write!(f, "null"),
+            Value::String(s) => write_string(f, s),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(object) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in object.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn error(&mut self, message: &str) -> Error {
+        let offset = self.chars.peek().map_or(self.input.len(), |&(i, _)| i);
+        Error::Parse(format!("json at byte {offset}: {message}"))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.chars.next_if(|&(_, c)| c == expected) {
+            Some(_) => Ok(()),
+            None => Err(self.error(&format!("expected '{expected}'"))),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> Result<Value> {
+        for expected in keyword.chars() {
+            if self.chars.next_if(|&(_, c)| c == expected).is_none() {
+                return Err(self.error(&format!("expected {keyword}")));
+            }
+        }
+        Ok(value)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(out),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, 'n')) => out.push('\n'),
+                    Some((_, 'r')) => out.push('\r'),
+                    Some((_, 't')) => out.push('\t'),
+                    Some((_, 'u')) => {
+                        let hex: String = (0..4)
+                            .filter_map(|_| self.chars.next())
+                            .map(|(_, c)| c)
+                            .collect();
+                        let c = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                        out.push(c.ok_or(self.error("invalid unicode escape"))?);
+                    }
+                    Some((_, c)) => out.push(c),
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some((_, c)) => out.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value> {
+        let start = self.chars.peek().map_or(self.input.len(), |&(i, _)| i);
+        while self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_digit() || "+-.eE".contains(*c))
+            .is_some()
+        {}
+        let end = self.chars.peek().map_or(self.input.len(), |&(i, _)| i);
+        let s = &self.input[start..end];
+        if let Ok(n) = s.parse() {
+            Ok(Value::Integer(n))
+        } else if let Ok(x) = s.parse() {
+            Ok(Value::Float(x))
+        } else {
+            Err(self.error(&format!("invalid number: {s}")))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        match self.chars.peek().map(|&(_, c)| c) {
+            Some('n') => self.keyword("null", Value::Null),
+            Some('t') => self.keyword("true", Value::Boolean(true)),
+            Some('f') => self.keyword("false", Value::Boolean(false)),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some('[') => {
+                self.chars.next();
+                let mut values = Vec::new();
+                self.skip_whitespace();
+                if self.chars.next_if(|&(_, c)| c == ']').is_some() {
+                    return Ok(Value::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+                    match self.chars.next() {
+                        Some((_, ',')) => continue,
+                        Some((_, ']')) => return Ok(Value::Array(values)),
+                        _ => return Err(self.error("expected ',' or ']'")),
+                    }
+                }
+            }
+            Some('{') => {
+                self.chars.next();
+                let mut object = Object::new();
+                self.skip_whitespace();
+                if self.chars.next_if(|&(_, c)| c == '}').is_some() {
+                    return Ok(Value::Object(object));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.expect(':')?;
+                    object.insert(key, self.value()?);
+                    self.skip_whitespace();
+                    match self.chars.next() {
+                        Some((_, ',')) => continue,
+                        Some((_, '}')) => return Ok(Value::Object(object)),
+                        _ => return Err(self.error("expected ',' or '}'")),
+                    }
+                }
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Value> {
+    let mut parser = Parser {
+        input,
+        chars: input.char_indices().peekable(),
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.chars.peek() {
+        None => Ok(value),
+        Some(_) => Err(parser.error("trailing characters")),
+    }
+}
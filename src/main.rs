@@ -1,6 +1,8 @@
-use adventofcode::*;
+use std::process::ExitCode;
 
-fn main() {
-    day25::solution1();
-    day25::solution2();
+use adventofcode::runner;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    runner::main(&args)
 }
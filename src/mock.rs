@@ -0,0 +1,230 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::answer::Answer;
+
+/// The puzzles a [`MockServer`] knows about.
+#[derive(Debug, Clone)]
+pub struct Site {
+    pub inputs: BTreeMap<(u16, u8), String>,
+    pub answers: BTreeMap<(u16, u8, u8), Answer>,
+    /// How long a wrong answer locks out further submissions.
+    pub cooldown: Duration,
+}
+
+impl Default for Site {
+    fn default() -> Self {
+        Site {
+            inputs: BTreeMap::new(),
+            answers: BTreeMap::new(),
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+struct State {
+    site: Site,
+    solved: BTreeSet<(u16, u8, u8)>,
+    locked_until: Option<Instant>,
+}
+
+impl State {
+    /// The message the site would give for an answer, marking the part solved when it is right.
+    fn judge(&mut self, year: u16, day: u8, part: u8, answer: &Answer) -> Option<String> {
+        let expected = self.site.answers.get(&(year, day, part))?.clone();
+        let now = Instant::now();
+        if let Some(wait) = self
+            .locked_until
+            .and_then(|until| until.checked_duration_since(now))
+        {
+            return Some(format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {}s left to wait.",
+                wait.as_secs() + 1
+            ));
+        }
+        let unlocked = part == 1 || self.solved.contains(&(year, day, 1));
+        if !unlocked || self.solved.contains(&(year, day, part)) {
+            return Some(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+                    .to_string(),
+            );
+        }
+        if *answer == expected {
+            self.solved.insert((year, day, part));
+            return Some("That's the right answer!  You are one gold star closer.".to_string());
+        }
+        self.locked_until = Some(now + self.site.cooldown);
+        let hint = match (answer.as_i128(), expected.as_i128()) {
+            (Some(given), Some(expected)) if given > expected => "; your answer is too high",
+            (Some(given), Some(expected)) if given < expected => "; your answer is too low",
+            _ => "",
+        };
+        Some(format!(
+            "That's not the right answer{hint}.  If you're stuck, make sure you're using the \
+             full input data."
+        ))
+    }
+}
+
+/// A stand-in for adventofcode.com on localhost, serving inputs and judging answers the way the
+/// site does, so that fetching and submitting can be exercised without a network.
+pub struct MockServer {
+    url: String,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn start(site: Site, address: impl ToSocketAddrs) -> io::Result<MockServer> {
+        let listener = TcpListener::bind(address)?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let stop = Arc::new(AtomicBool::new(false));
+        let state = Mutex::new(State {
+            site,
+            solved: BTreeSet::new(),
+            locked_until: None,
+        });
+        let stopped = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let _ = handle(stream, &state);
+                }
+            }
+        });
+        Ok(MockServer {
+            url,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Serves until the process ends.
+    pub fn wait(mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        let address = self.url.trim_start_matches("http://");
+        let _ = TcpStream::connect(address);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Decodes a `application/x-www-form-urlencoded` value.
+fn decode(s: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = s.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        rest = tail;
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' if rest.len() >= 2 => {
+                let hex = std::str::from_utf8(&rest[..2]).unwrap_or_default();
+                match u8::from_str_radix(hex, 16) {
+                    Ok(decoded) => {
+                        bytes.push(decoded);
+                        rest = &rest[2..];
+                    }
+                    Err(_) => bytes.push(b),
+                }
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(": ") {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.parse().unwrap_or(0);
+            }
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8_lossy(&body);
+
+    let (method, path) = request
+        .split_once(' ')
+        .map(|(method, rest)| (method, rest.split(' ').next().unwrap_or_default()))
+        .unwrap_or_default();
+    let route: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let puzzle = match route[..] {
+        [year, "day", day, _] => year.parse::<u16>().ok().zip(day.parse::<u8>().ok()),
+        _ => None,
+    };
+    let mut state = state.lock().unwrap();
+    match (method, puzzle, route.last()) {
+        ("GET", Some(puzzle), Some(&"input")) => match state.site.inputs.get(&puzzle) {
+            Some(input) => respond(&mut stream, "200 OK", &format!("{input}\n")),
+            None => respond(&mut stream, "404 Not Found", "Not Found"),
+        },
+        ("POST", Some((year, day)), Some(&"answer")) => {
+            let form: BTreeMap<&str, String> = body
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(key, value)| (key, decode(value)))
+                .collect();
+            let part = form.get("level").and_then(|level| level.parse().ok());
+            let answer = form.get("answer").map(|answer| answer.parse().unwrap());
+            let message = part
+                .zip(answer)
+                .and_then(|(part, answer)| state.judge(year, day, part, &answer));
+            match message {
+                Some(message) => respond(
+                    &mut stream,
+                    "200 OK",
+                    &format!("<main>\n<article><p>{message}</p></article>\n</main>\n"),
+                ),
+                None => respond(&mut stream, "400 Bad Request", "Bad Request"),
+            }
+        }
+        _ => respond(&mut stream, "404 Not Found", "Not Found"),
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    error::Result,
+    render::Frames,
+    solution::{self, Params, Parsed, Solution},
+    *,
+};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str, &Params) -> Result<Box<dyn Parsed>>,
+    pub frames: fn(&str, &Params) -> Result<Frames>,
+}
+
+fn parse<S: Solution + 'static, const DAY: u8>(
+    input: &str,
+    params: &Params,
+) -> Result<Box<dyn Parsed>>
+where
+    S::Model: Sync + 'static,
+{
+    solution::parse::<S>(input, params).map_err(|error| error.on_day(DAY))
+}
+
+fn frames<S: Solution, const DAY: u8>(input: &str, params: &Params) -> Result<Frames> {
+    S::parse_with(input, params)
+        .and_then(|model| S::frames(&model, params))
+        .map_err(|error| error.on_day(DAY))
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            parse: parse::<$solution, $day>,
+            frames: frames::<$solution, $day>,
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(2024, 1, y2024::day01::Day01),
+    day!(2024, 2, y2024::day02::Day02),
+    day!(2024, 3, y2024::day03::Day03),
+    day!(2024, 4, y2024::day04::Day04),
+    day!(2024, 5, y2024::day05::Day05),
+    day!(2024, 6, y2024::day06::Day06),
+    day!(2024, 7, y2024::day07::Day07),
+    day!(2024, 8, y2024::day08::Day08),
+    day!(2024, 9, y2024::day09::Day09),
+    day!(2024, 10, y2024::day10::Day10),
+    day!(2024, 11, y2024::day11::Day11),
+    day!(2024, 12, y2024::day12::Day12),
+    day!(2024, 13, y2024::day13::Day13),
+    day!(2024, 14, y2024::day14::Day14),
+    day!(2024, 15, y2024::day15::Day15),
+    day!(2024, 16, y2024::day16::Day16),
+    day!(2024, 17, y2024::day17::Day17),
+    day!(2024, 18, y2024::day18::Day18),
+    day!(2024, 19, y2024::day19::Day19),
+    day!(2024, 20, y2024::day20::Day20),
+    day!(2024, 21, y2024::day21::Day21),
+    day!(2024, 22, y2024::day22::Day22),
+    day!(2024, 23, y2024::day23::Day23),
+    day!(2024, 24, y2024::day24::Day24),
+    day!(2024, 25, y2024::day25::Day25),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+pub fn days(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |entry| entry.year == year)
+}
+
+/// Every year with a registered solution, oldest first.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|entry| entry.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+pub fn latest_year() -> u16 {
+    *years().last().unwrap()
+}
@@ -0,0 +1,311 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, BufWriter, Write},
+    iter,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    error::{Error, Result},
+    utils::Grid,
+};
+
+/// Successive states of a simulation, one grid of cell chars per step.
+pub type Frames = Box<dyn Iterator<Item = Grid<char>>>;
+
+pub type Rgb = [u8; 3];
+
+/// The colour of every cell char, with a fallback for chars it does not list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: BTreeMap<char, Rgb>,
+    fallback: Rgb,
+}
+
+impl Palette {
+    pub fn new(fallback: Rgb) -> Palette {
+        Palette {
+            colors: BTreeMap::new(),
+            fallback,
+        }
+    }
+
+    pub fn set(&mut self, c: char, color: Rgb) {
+        self.colors.insert(c, color);
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        *self.colors.get(&c).unwrap_or(&self.fallback)
+    }
+
+    /// Every distinct colour, the fallback first.
+    fn colors(&self) -> Vec<Rgb> {
+        let mut colors = vec![self.fallback];
+        for color in self.colors.values() {
+            if !colors.contains(color) {
+                colors.push(*color);
+            }
+        }
+        colors
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let mut palette = Palette::new([255, 255, 255]);
+        for c in [' ', '.'] {
+            palette.set(c, [0, 0, 0]);
+        }
+        palette.set('#', [160, 160, 160]);
+        palette.set('@', [255, 64, 64]);
+        palette.set('X', [48, 96, 224]);
+        for c in ['O', '[', ']'] {
+            palette.set(c, [200, 140, 60]);
+        }
+        for c in ['^', '>', 'v', '<'] {
+            palette.set(c, [255, 220, 0]);
+        }
+        let digits = [
+            [230, 25, 75],
+            [60, 180, 75],
+            [255, 225, 25],
+            [0, 130, 200],
+            [245, 130, 48],
+            [145, 30, 180],
+            [70, 240, 240],
+            [240, 50, 230],
+            [210, 245, 60],
+            [250, 190, 212],
+        ];
+        for (c, color) in ('0'..='9').zip(digits) {
+            palette.set(c, color);
+        }
+        palette
+    }
+}
+
+/// Overrides on top of the default palette, written as `#=ffffff,.=000000`.
+impl FromStr for Palette {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut palette = Palette::default();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            let invalid = || Error::Parse(format!("invalid palette entry for {c:?} in {s:?}"));
+            if chars.next() != Some('=') {
+                return Err(invalid());
+            }
+            let hex: String = chars.by_ref().take(6).collect();
+            if hex.len() != 6 || !hex.chars().all(|h| h.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            let rgb = u32::from_str_radix(&hex, 16).map_err(|_| invalid())?;
+            palette.set(c, [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8]);
+            match chars.next() {
+                None | Some(',') => (),
+                Some(_) => return Err(invalid()),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// Writes one frame as a binary PPM image, each cell drawn as a `scale` pixel square.
+pub fn ppm(
+    out: &mut impl Write,
+    frame: &Grid<char>,
+    palette: &Palette,
+    scale: usize,
+) -> io::Result<()> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    for row in frame.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|&c| iter::repeat_n(palette.color(c), scale))
+            .flatten()
+            .collect();
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the frames to `dir` as `frame_00000.ppm`, `frame_00001.ppm` and so on.
+pub fn write_frames(
+    dir: &Path,
+    frames: impl IntoIterator<Item = Grid<char>>,
+    palette: &Palette,
+    scale: usize,
+) -> Result<usize> {
+    fs::create_dir_all(dir).map_err(Error::io(dir))?;
+    let mut count = 0;
+    for frame in frames {
+        let path = dir.join(format!("frame_{count:05}.ppm"));
+        let file = fs::File::create(&path).map_err(Error::io(&path))?;
+        let mut out = BufWriter::new(file);
+        ppm(&mut out, &frame, palette, scale)
+            .and_then(|_| out.flush())
+            .map_err(Error::io(&path))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Packs variable width codes least significant bit first, as GIF expects.
+#[derive(Default)]
+struct Bits {
+    bytes: Vec<u8>,
+    buffer: u32,
+    length: u32,
+}
+
+impl Bits {
+    fn push(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.length;
+        self.length += size;
+        while self.length >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.length -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.length > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// LZW compresses colour indices, clearing the table whenever it fills up.
+fn lzw(indices: &[u8], min_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_size;
+    let end = clear + 1;
+    let mut bits = Bits::default();
+    let mut table = HashMap::<(u16, u8), u16>::new();
+    let mut next = end + 1;
+    let mut size = min_size + 1;
+    bits.push(clear, size);
+    let Some((&first, rest)) = indices.split_first() else {
+        bits.push(end, size);
+        return bits.finish();
+    };
+    let mut prefix = first as u16;
+    for &index in rest {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        bits.push(prefix, size);
+        if next < 4096 {
+            table.insert((prefix, index), next);
+            next += 1;
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        } else {
+            bits.push(clear, size);
+            table.clear();
+            next = end + 1;
+            size = min_size + 1;
+        }
+        prefix = index as u16;
+    }
+    bits.push(prefix, size);
+    bits.push(end, size);
+    bits.finish()
+}
+
+/// Writes the frames as a looping GIF animation, `delay` hundredths of a second apart.
+pub fn gif(
+    out: &mut impl Write,
+    frames: impl IntoIterator<Item = Grid<char>>,
+    palette: &Palette,
+    scale: usize,
+    delay: u16,
+) -> io::Result<usize> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(invalid("no frames to render"));
+    };
+    let (width, height) = (first.width(), first.height());
+    let (pixels_x, pixels_y) = (width * scale, height * scale);
+    if pixels_x > u16::MAX as usize || pixels_y > u16::MAX as usize {
+        return Err(invalid("frames are too large for a GIF"));
+    }
+    let colors = palette.colors();
+    if colors.len() > 256 {
+        return Err(invalid("a GIF palette holds at most 256 colours"));
+    }
+    let depth = (colors.len().next_power_of_two().trailing_zeros()).max(1);
+    let indices: HashMap<Rgb, u8> = colors
+        .iter()
+        .enumerate()
+        .map(|(i, &color)| (color, i as u8))
+        .collect();
+
+    out.write_all(b"GIF89a")?;
+    out.write_all(&(pixels_x as u16).to_le_bytes())?;
+    out.write_all(&(pixels_y as u16).to_le_bytes())?;
+    out.write_all(&[0xf0 | (depth as u8 - 1), 0, 0])?;
+    for i in 0..1 << depth {
+        out.write_all(colors.get(i).unwrap_or(&[0, 0, 0]))?;
+    }
+    out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+    let mut count = 0;
+    for frame in frames {
+        if (frame.width(), frame.height()) != (width, height) {
+            return Err(invalid("every frame must have the same size"));
+        }
+        let mut pixels = Vec::with_capacity(pixels_x * pixels_y);
+        for row in frame.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&c| iter::repeat_n(indices[&palette.color(c)], scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        out.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        out.write_all(&delay.to_le_bytes())?;
+        out.write_all(&[0x00, 0x00, 0x2c, 0, 0, 0, 0])?;
+        out.write_all(&(pixels_x as u16).to_le_bytes())?;
+        out.write_all(&(pixels_y as u16).to_le_bytes())?;
+        let min_size = depth.max(2);
+        out.write_all(&[0x00, min_size as u8])?;
+        for block in lzw(&pixels, min_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0x00])?;
+        count += 1;
+    }
+    out.write_all(&[0x3b])?;
+    Ok(count)
+}
+
+pub fn write_gif(
+    path: &Path,
+    frames: impl IntoIterator<Item = Grid<char>>,
+    palette: &Palette,
+    scale: usize,
+    delay: u16,
+) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(Error::io(parent))?;
+    }
+    let file = fs::File::create(path).map_err(Error::io(path))?;
+    let mut out = BufWriter::new(file);
+    let count = gif(&mut out, frames, palette, scale, delay).map_err(Error::io(path))?;
+    out.flush().map_err(Error::io(path))?;
+    Ok(count)
+}
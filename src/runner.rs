@@ -0,0 +1,1042 @@
+use std::{
+    io, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    answer::Answer,
+    answers::{self, Answers, Status},
+    bench::{self, Baseline},
+    differential,
+    error::Error,
+    history::{self, Attempt, History},
+    input::{self, Cache, InputSource, Stdin},
+    json::{Object, Value},
+    mock::{MockServer, Site},
+    registry::{self, Day},
+    render::{self, Palette},
+    scaffold,
+    solution::Params,
+    submit::{self, Http, Submitter, Verdict},
+    viewer,
+};
+
+pub const USAGE: &str = "\
+usage:
+    adventofcode list [YEAR]
+    adventofcode run [YEAR] <all|DAY|FROM..TO|FROM..=TO> [PART] [OPTIONS]
+    adventofcode verify [YEAR] <all|DAY|FROM..TO|FROM..=TO> [PART] [OPTIONS]
+    adventofcode record [YEAR] <all|DAY|FROM..TO|FROM..=TO> [PART] [OPTIONS]
+    adventofcode bench [YEAR] <all|DAY|FROM..TO|FROM..=TO> [PART] [OPTIONS]
+    adventofcode compare [YEAR] <all|DAY|FROM..TO|FROM..=TO> [OPTIONS]
+    adventofcode render [YEAR] <DAY> --out <PATH> [OPTIONS]
+    adventofcode view [YEAR] <DAY> [OPTIONS]
+    adventofcode submit [YEAR] <DAY> <PART> [OPTIONS]
+    adventofcode history [YEAR] [DAY]
+    adventofcode serve [--port <PORT>]
+    adventofcode new <YEAR> <DAY>
+
+The year defaults to the latest one with solutions for the selected days.
+
+options:
+    --input <PATH>        read the puzzle input from PATH, or from stdin if PATH is -
+    --format <FORMAT>     run: print a table (text, the default) or one JSON object per part (json)
+    --offline             only use inputs already cached under inputs/
+    --iterations <N>      bench: run every phase N times (default 10)
+    --save <PATH>         bench: write the timings to PATH as a JSON baseline
+    --compare <PATH>      bench: compare median timings against a saved baseline
+    --threshold <PCT>     bench: slowdown in percent reported as a regression (default 10)
+    --out <PATH>          render: write an animated GIF if PATH ends in .gif, else PPM frames into PATH
+    --palette <SPEC>      render: cell colours over the defaults, such as '#=ffffff,.=000000'
+    --scale <N>           render: pixels per cell (default 4)
+    --every <N>           render: keep every Nth frame (default 1)
+    --param <KEY=VALUE>   a puzzle parameter, such as width=11 and height=7 for the day 14 example
+    --server <URL>        fetch inputs from and submit answers to URL instead of adventofcode.com
+    --port <PORT>         serve: listen on PORT rather than any free port
+
+serve stands in for adventofcode.com on localhost, with the inputs under inputs/ and the answers
+under answers/, so that submit --server can be tried without a network.
+
+submit keeps every answer it sends under submissions/, and will not send one the site has already
+rejected or that lies outside the too high and too low answers given so far. history shows them.";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub input: Option<PathBuf>,
+    pub format: Format,
+    pub offline: bool,
+    pub iterations: Option<usize>,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+    pub threshold: Option<u32>,
+    pub out: Option<PathBuf>,
+    pub palette: Option<Palette>,
+    pub scale: Option<usize>,
+    pub every: Option<usize>,
+    pub params: Params,
+    pub server: Option<String>,
+    pub port: Option<u16>,
+}
+
+impl Options {
+    fn source(&self) -> Box<dyn InputSource> {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => Box::new(Stdin),
+            Some(path) => Box::new(input::File(path.clone())),
+            None => Box::new(Cache::new(
+                "inputs",
+                (!self.offline).then(|| self.submitter()),
+            )),
+        }
+    }
+
+    fn submitter(&self) -> Box<dyn Submitter> {
+        Box::new(Http::new(self.server.as_deref().unwrap_or(submit::SITE)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Run,
+    Verify,
+    Record,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    List {
+        year: Option<u16>,
+    },
+    Solve {
+        action: Action,
+        year: u16,
+        days: Vec<u8>,
+        parts: Vec<u8>,
+        options: Options,
+    },
+    Bench {
+        year: u16,
+        days: Vec<u8>,
+        parts: Vec<u8>,
+        options: Options,
+    },
+    Compare {
+        year: u16,
+        days: Vec<u8>,
+        options: Options,
+    },
+    Render {
+        year: u16,
+        day: u8,
+        options: Options,
+    },
+    View {
+        year: u16,
+        day: u8,
+        options: Options,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        options: Options,
+    },
+    Serve {
+        options: Options,
+    },
+    History {
+        year: u16,
+        day: Option<u8>,
+    },
+    New {
+        year: u16,
+        day: u8,
+    },
+}
+
+/// Separates `--flag [value]` options from positional arguments.
+fn read_options(args: &[String]) -> Result<(Vec<&str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut options = Options::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                options.input = Some(PathBuf::from(path));
+            }
+            "--format" => {
+                let format = args.next().ok_or("--format requires text or json")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format: {format}")),
+                };
+            }
+            "--offline" => options.offline = true,
+            "--iterations" => {
+                let n = args.next().ok_or("--iterations requires a count")?;
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("invalid iteration count: {n}"))?;
+                options.iterations = Some(n);
+            }
+            "--save" => {
+                let path = args.next().ok_or("--save requires a path")?;
+                options.save = Some(PathBuf::from(path));
+            }
+            "--compare" => {
+                let path = args.next().ok_or("--compare requires a path")?;
+                options.compare = Some(PathBuf::from(path));
+            }
+            "--threshold" => {
+                let pct = args.next().ok_or("--threshold requires a percentage")?;
+                let pct = pct
+                    .parse()
+                    .map_err(|_| format!("invalid threshold: {pct}"))?;
+                options.threshold = Some(pct);
+            }
+            "--out" => {
+                let path = args.next().ok_or("--out requires a path")?;
+                options.out = Some(PathBuf::from(path));
+            }
+            "--palette" => {
+                let spec = args.next().ok_or("--palette requires a palette")?;
+                options.palette = Some(spec.parse().map_err(|e: Error| e.to_string())?);
+            }
+            "--scale" => {
+                let n = args.next().ok_or("--scale requires a size")?;
+                let n = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("invalid scale: {n}"))?;
+                options.scale = Some(n);
+            }
+            "--every" => {
+                let n = args.next().ok_or("--every requires a count")?;
+                let n = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("invalid frame interval: {n}"))?;
+                options.every = Some(n);
+            }
+            "--param" => {
+                let param = args.next().ok_or("--param requires KEY=VALUE")?;
+                let (key, value) = param
+                    .split_once('=')
+                    .ok_or(format!("invalid parameter: {param}"))?;
+                options.params.insert(key, value);
+            }
+            "--server" => {
+                let url = args.next().ok_or("--server requires a URL")?;
+                options.server = Some(url.clone());
+            }
+            "--port" => {
+                let port = args.next().ok_or("--port requires a port")?;
+                let port = port.parse().map_err(|_| format!("invalid port: {port}"))?;
+                options.port = Some(port);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option: {flag}")),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+    Ok((positional, options))
+}
+
+fn read_action(s: &str) -> Option<Action> {
+    match s {
+        "run" => Some(Action::Run),
+        "verify" => Some(Action::Verify),
+        "record" => Some(Action::Record),
+        _ => None,
+    }
+}
+
+impl TryFrom<&[String]> for Command {
+    type Error = String;
+
+    fn try_from(args: &[String]) -> Result<Self, Self::Error> {
+        let (positional, options) = read_options(args)?;
+        if let ["new", year, day] = positional[..] {
+            if options != Options::default() {
+                return Err("new takes no options".to_string());
+            }
+            return Ok(Command::New {
+                year: year
+                    .parse()
+                    .ok()
+                    .filter(|year| (2015..10000).contains(year))
+                    .ok_or(format!("invalid year: {year}"))?,
+                day: day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or(format!("invalid day: {day}"))?,
+            });
+        }
+        let (chosen, positional) = read_year(positional)?;
+        let year = chosen.unwrap_or_else(|| default_year(positional.get(1).copied()));
+        match positional[..] {
+            ["list"] => Ok(Command::List { year: chosen }),
+            ["serve"] => Ok(Command::Serve { options }),
+            ["history", ref day @ ..] if day.len() <= 1 && options == Options::default() => {
+                Ok(Command::History {
+                    year,
+                    day: day.first().map(|day| read_day(year, day)).transpose()?,
+                })
+            }
+            ["submit", day, part] => Ok(Command::Submit {
+                year,
+                day: read_day(year, day)?,
+                part: read_part(part)?,
+                options,
+            }),
+            ["compare", selection] => {
+                let days = read_selection(year, selection)?;
+                if options.input.is_some() && days.len() > 1 {
+                    return Err("--input can only be used with a single day".to_string());
+                }
+                Ok(Command::Compare {
+                    year,
+                    days,
+                    options,
+                })
+            }
+            ["render", day] => {
+                if options.out.is_none() {
+                    return Err("render requires --out".to_string());
+                }
+                Ok(Command::Render {
+                    year,
+                    day: read_day(year, day)?,
+                    options,
+                })
+            }
+            ["view", day] => {
+                if options
+                    .input
+                    .as_ref()
+                    .is_some_and(|path| path.as_os_str() == "-")
+                {
+                    return Err("view reads its commands from stdin, so --input cannot".to_string());
+                }
+                Ok(Command::View {
+                    year,
+                    day: read_day(year, day)?,
+                    options,
+                })
+            }
+            [command, selection, ref rest @ ..] if rest.len() <= 1 => {
+                let action = match command {
+                    "bench" => None,
+                    _ => Some(read_action(command).ok_or(USAGE)?),
+                };
+                let days = read_selection(year, selection)?;
+                let parts = match rest.first() {
+                    Some(part) => vec![read_part(part)?],
+                    None => vec![1, 2],
+                };
+                if options.input.is_some() && days.len() > 1 {
+                    return Err("--input can only be used with a single day".to_string());
+                }
+                if options.format == Format::Json && action != Some(Action::Run) {
+                    return Err("--format json can only be used with run".to_string());
+                }
+                Ok(match action {
+                    Some(action) => Command::Solve {
+                        action,
+                        year,
+                        days,
+                        parts,
+                        options,
+                    },
+                    None => Command::Bench {
+                        year,
+                        days,
+                        parts,
+                        options,
+                    },
+                })
+            }
+            _ => Err(USAGE.to_string()),
+        }
+    }
+}
+
+/// Takes the year out of the arguments when one follows the command.
+fn read_year(mut positional: Vec<&str>) -> Result<(Option<u16>, Vec<&str>), String> {
+    let year = match positional.get(1) {
+        Some(year) if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) => {
+            year.parse().unwrap()
+        }
+        _ => return Ok((None, positional)),
+    };
+    if !registry::years().contains(&year) {
+        return Err(format!("no solutions registered for {year}"));
+    }
+    positional.remove(1);
+    Ok((Some(year), positional))
+}
+
+/// The latest year with a solution for every selected day, so that starting on a new year does not
+/// hide the days of the last one.
+fn default_year(selection: Option<&str>) -> u16 {
+    registry::years()
+        .into_iter()
+        .rev()
+        .find(|&year| selection.is_none_or(|s| read_selection(year, s).is_ok()))
+        .unwrap_or_else(registry::latest_year)
+}
+
+fn read_day(year: u16, s: &str) -> Result<u8, String> {
+    let day: u8 = s.parse().map_err(|_| format!("invalid day: {s}"))?;
+    registry::find(year, day)
+        .map(|entry| entry.day)
+        .ok_or(format!("no solution registered for {year} day {day}"))
+}
+
+fn read_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part: {s}")),
+    }
+}
+
+/// Accepts `all`, a single day, or a Rust-style range such as `3..9` or `3..=9`.
+pub fn read_selection(year: u16, s: &str) -> Result<Vec<u8>, String> {
+    if s == "all" {
+        return Ok(registry::days(year).map(|entry| entry.day).collect());
+    }
+    let days: Vec<u8> = if let Some((from, to)) = s.split_once("..=") {
+        (read_day(year, from)?..=read_day(year, to)?).collect()
+    } else if let Some((from, to)) = s.split_once("..") {
+        (read_day(year, from)?..read_day(year, to)?).collect()
+    } else {
+        vec![read_day(year, s)?]
+    };
+    let days: Vec<u8> = days
+        .into_iter()
+        .filter(|&day| registry::find(year, day).is_some())
+        .collect();
+    if days.is_empty() {
+        Err(format!("empty selection: {s}"))
+    } else {
+        Ok(days)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(Answer),
+    Unimplemented,
+    Failed(String),
+    Panicked,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Answer(_) => "ok",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Failed(_) => "failed",
+            Outcome::Panicked => "panicked",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed(_) | Outcome::Panicked)
+    }
+}
+
+pub struct Part {
+    pub part: u8,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+pub struct Solved {
+    pub input: String,
+    pub parts: Vec<Part>,
+}
+
+/// Parses and solves the selected parts of one day, running the parts in parallel and isolating
+/// panics.
+pub fn solve(entry: &Day, parts: &[u8], input: String, params: &Params) -> Result<Solved, String> {
+    let parsed = match panic::catch_unwind(|| (entry.parse)(&input, params)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(error)) => return Err(error.to_string()),
+        Err(_) => return Err("panicked while parsing".to_string()),
+    };
+    let parts = parts
+        .par_iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| parsed.part(part)));
+            let elapsed = start.elapsed();
+            let outcome = match result {
+                Ok(Ok(answer)) => Outcome::Answer(answer),
+                Ok(Err(Error::Unimplemented)) => Outcome::Unimplemented,
+                Ok(Err(error)) => Outcome::Failed(error.to_string()),
+                Err(_) => Outcome::Panicked,
+            };
+            Part {
+                part,
+                outcome,
+                elapsed,
+            }
+        })
+        .collect();
+    Ok(Solved { input, parts })
+}
+
+/// Reads the inputs one at a time, then solves every selected day in parallel.
+pub fn solve_all(
+    year: u16,
+    days: &[u8],
+    parts: &[u8],
+    source: &dyn InputSource,
+    params: &Params,
+) -> Vec<(u8, Result<Solved, String>)> {
+    let inputs: Vec<(u8, Result<String, String>)> = days
+        .iter()
+        .map(|&day| (day, source.read(year, day).map_err(|e| e.to_string())))
+        .collect();
+    inputs
+        .into_par_iter()
+        .map(|(day, input)| {
+            let entry = registry::find(year, day).unwrap();
+            (
+                day,
+                input.and_then(|input| solve(entry, parts, input, params)),
+            )
+        })
+        .collect()
+}
+
+/// Prints the answer, time and status of every part and returns how many of them failed.
+fn summary(results: &[(u8, Result<Solved, String>)], parts: &[u8], elapsed: Duration) -> usize {
+    let mut failures = 0;
+    println!(
+        "{:<4} {:<4} {:<13} {:>12}  Answer",
+        "Day", "Part", "Status", "Time"
+    );
+    for (day, solved) in results {
+        let solved = match solved {
+            Ok(solved) => solved,
+            Err(error) => {
+                for part in parts {
+                    println!(
+                        "{:<4} {:<4} {:<13} {:>12}  {}",
+                        format!("{day:02}"),
+                        part,
+                        "failed",
+                        "",
+                        error
+                    );
+                }
+                failures += parts.len();
+                continue;
+            }
+        };
+        for part in solved.parts.iter() {
+            let answer = match &part.outcome {
+                Outcome::Answer(answer) => answer.to_string(),
+                Outcome::Failed(error) => error.clone(),
+                _ => String::new(),
+            };
+            failures += part.outcome.is_failure() as usize;
+            println!(
+                "{:<4} {:<4} {:<13} {:>12}  {}",
+                format!("{day:02}"),
+                part.part,
+                part.outcome.status(),
+                format!("{:.3?}", part.elapsed),
+                answer
+            );
+        }
+    }
+    println!("Total wall clock time: {elapsed:.3?}");
+    failures
+}
+
+/// Integer answers become JSON numbers, anything else a string.
+fn answer_value(answer: &Answer) -> Value {
+    match answer.as_i128() {
+        Some(n) => Value::Integer(n),
+        None => Value::String(answer.to_string()),
+    }
+}
+
+/// Prints one JSON object per line for every part and returns how many of them failed.
+fn json_lines(year: u16, results: &[(u8, Result<Solved, String>)], parts: &[u8]) -> usize {
+    let mut failures = 0;
+    for (day, solved) in results {
+        for &part in parts {
+            let mut object = Object::new();
+            object.insert("year".to_string(), Value::Integer(year as i128));
+            object.insert("day".to_string(), Value::Integer(*day as i128));
+            object.insert("part".to_string(), Value::Integer(part as i128));
+            let (status, answer, error) = match solved {
+                Ok(solved) => {
+                    let solved_part = solved.parts.iter().find(|p| p.part == part).unwrap();
+                    let hash = Value::String(answers::hash(&solved.input));
+                    object.insert("input_hash".to_string(), hash);
+                    let elapsed = Value::Integer(solved_part.elapsed.as_nanos() as i128);
+                    object.insert("elapsed_ns".to_string(), elapsed);
+                    let outcome = &solved_part.outcome;
+                    match outcome {
+                        Outcome::Answer(answer) => (outcome.status(), answer_value(answer), None),
+                        Outcome::Failed(error) => (outcome.status(), Value::Null, Some(error)),
+                        _ => (outcome.status(), Value::Null, None),
+                    }
+                }
+                Err(error) => ("failed", Value::Null, Some(error)),
+            };
+            failures += (status == "failed" || status == "panicked") as usize;
+            object.insert("status".to_string(), Value::String(status.to_string()));
+            object.insert("answer".to_string(), answer);
+            if let Some(error) = error {
+                object.insert("error".to_string(), Value::String(error.clone()));
+            }
+            println!("{}", Value::Object(object));
+        }
+    }
+    failures
+}
+
+/// Prints one line per solved part and returns how many of them failed.
+fn report(action: Action, day: u8, solved: &Solved, answers: &mut Answers) -> usize {
+    let hash = answers::hash(&solved.input);
+    let mut failures = 0;
+    for Part { part, outcome, .. } in solved.parts.iter() {
+        let message = match outcome {
+            Outcome::Answer(answer) => match action {
+                Action::Run => answer.to_string(),
+                Action::Verify => {
+                    let status = answers.check(day, *part, &hash, answer);
+                    failures += matches!(status, Status::Fail(_)) as usize;
+                    format!("{status}: {answer}")
+                }
+                Action::Record => {
+                    answers.record(day, *part, &hash, answer);
+                    format!("recorded {answer}")
+                }
+            },
+            Outcome::Unimplemented => Error::Unimplemented.to_string(),
+            Outcome::Failed(error) => {
+                failures += 1;
+                format!("error: {error}")
+            }
+            Outcome::Panicked => {
+                failures += 1;
+                "panicked".to_string()
+            }
+        };
+        println!("Day {:02} part {}: {}", day, part, message);
+    }
+    failures
+}
+
+fn list(year: Option<u16>) -> ExitCode {
+    let years = match year {
+        Some(year) => vec![year],
+        None => registry::years(),
+    };
+    for year in years {
+        for entry in registry::days(year) {
+            println!("{} day {:02}", entry.year, entry.day);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn execute(
+    action: Action,
+    year: u16,
+    days: &[u8],
+    parts: &[u8],
+    options: &Options,
+) -> Result<usize, Error> {
+    let source = options.source();
+    let path = Answers::path(answers::DIR, year);
+    let mut answers = Answers::load(&path)?;
+    let start = Instant::now();
+    let results = solve_all(year, days, parts, source.as_ref(), &options.params);
+    if action == Action::Run {
+        return Ok(match options.format {
+            Format::Text => summary(&results, parts, start.elapsed()),
+            Format::Json => json_lines(year, &results, parts),
+        });
+    }
+    let mut failures = 0;
+    for (day, solved) in results {
+        match solved {
+            Ok(solved) => failures += report(action, day, &solved, &mut answers),
+            Err(error) => {
+                println!("Day {:02}: {}", day, error);
+                failures += parts.len();
+            }
+        }
+    }
+    if action == Action::Record {
+        answers.save(&path)?;
+    }
+    Ok(failures)
+}
+
+/// Prints a min/median/max table and returns how many phases regressed against the baseline.
+fn bench(year: u16, days: &[u8], parts: &[u8], options: &Options) -> Result<usize, Error> {
+    let source = options.source();
+    let iterations = options.iterations.unwrap_or(10);
+    let threshold = options.threshold.unwrap_or(10) as f64;
+    let baseline = match &options.compare {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let mut results = Baseline::default();
+    let mut regressions = 0;
+    let change = if baseline.is_some() { " change" } else { "" };
+    println!(
+        "{:<4} {:<6} {:>12} {:>12} {:>12} {:>9}",
+        "Day", "Phase", "min", "median", "max", change
+    );
+    for &day in days {
+        let entry = registry::find(year, day).unwrap();
+        let input = source.read(year, day)?;
+        let timings = match bench::measure(entry, parts, &input, &options.params, iterations) {
+            Ok(timings) => timings,
+            Err(error) => {
+                println!("{:<4} {}", format!("{:02}", day), error);
+                continue;
+            }
+        };
+        for (phase, stats) in timings.phases.iter() {
+            let mut line = format!(
+                "{:<4} {:<6} {:>12} {:>12} {:>12}",
+                format!("{:02}", day),
+                phase,
+                format!("{:.3?}", stats.min),
+                format!("{:.3?}", stats.median),
+                format!("{:.3?}", stats.max),
+            );
+            if let Some(before) = baseline.as_ref().and_then(|b| b.get(year, day, phase)) {
+                let change = bench::change(*stats, before);
+                line += &format!(" {:>+8.1}%", change);
+                if change > threshold {
+                    line += " REGRESSION";
+                    regressions += 1;
+                }
+            }
+            println!("{line}");
+        }
+        results.days.push(timings);
+    }
+    if let Some(path) = &options.save {
+        results.save(path)?;
+    }
+    Ok(regressions)
+}
+
+/// Runs every alternate implementation of the selected days and returns how many parts diverged.
+fn compare(year: u16, days: &[u8], options: &Options) -> Result<usize, Error> {
+    let source = options.source();
+    let mut divergences = 0;
+    for &day in days {
+        let variants: Vec<_> = differential::find(year, day).collect();
+        if variants.is_empty() {
+            continue;
+        }
+        let input = source.read(year, day)?;
+        for variant in variants {
+            let found = differential::compare(variant, &input, &options.params);
+            for &part in variant.parts {
+                let message = match found.iter().find(|divergence| divergence.part == part) {
+                    Some(divergence) => format!("DIVERGED: {divergence}"),
+                    None => format!("{} agrees", variant.name),
+                };
+                println!("Day {:02} part {}: {}", day, part, message);
+            }
+            divergences += found.len();
+        }
+    }
+    Ok(divergences)
+}
+
+/// Writes the day's simulation to `--out` and returns how many frames were written.
+fn render(year: u16, day: u8, options: &Options) -> Result<usize, Error> {
+    let entry = registry::find(year, day).unwrap();
+    let input = options.source().read(year, day)?;
+    let frames = (entry.frames)(&input, &options.params)?.step_by(options.every.unwrap_or(1));
+    let palette = options.palette.clone().unwrap_or_default();
+    let scale = options.scale.unwrap_or(4);
+    let path = options.out.as_deref().unwrap();
+    if path.extension().is_some_and(|extension| extension == "gif") {
+        render::write_gif(path, frames, &palette, scale, 5)
+    } else {
+        render::write_frames(path, frames, &palette, scale)
+    }
+}
+
+fn view(year: u16, day: u8, options: &Options) -> Result<(), Error> {
+    let entry = registry::find(year, day).unwrap();
+    let input = options.source().read(year, day)?;
+    let frames = (entry.frames)(&input, &options.params)?;
+    viewer::view(
+        frames,
+        0,
+        0,
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+    )
+    .map_err(Error::io("stdout"))
+}
+
+/// Solves one part and submits the answer, returning what the site made of it.
+fn submit(year: u16, day: u8, part: u8, options: &Options) -> Result<Verdict, Error> {
+    let entry = registry::find(year, day).unwrap();
+    let input = options.source().read(year, day)?;
+    let answer = (entry.parse)(&input, &options.params)?.part(part)?;
+    let path = History::path(history::DIR, year);
+    let mut history = History::load(&path)?;
+    if let Some(reason) = history.refusal(day, part, &answer) {
+        return Err(Error::Input(format!(
+            "{year} day {day:02} part {part}: not submitting {answer}: {reason}"
+        )));
+    }
+    println!("{year} day {day:02} part {part}: submitting {answer}");
+    let verdict = options.submitter().submit(year, day, part, &answer)?;
+    history.append(&path, Attempt::new(day, part, answer, verdict.clone()))?;
+    Ok(verdict)
+}
+
+/// Lists the answers submitted so far, then what is known about the ones still to find.
+fn show_history(year: u16, day: Option<u8>) -> Result<(), Error> {
+    let history = History::load(&History::path(history::DIR, year))?;
+    let attempts: Vec<&Attempt> = history
+        .attempts
+        .iter()
+        .filter(|attempt| day.is_none_or(|day| attempt.day == day))
+        .collect();
+    if attempts.is_empty() {
+        match day {
+            Some(day) => println!("no answers submitted for {year} day {day:02}"),
+            None => println!("no answers submitted for {year}"),
+        }
+        return Ok(());
+    }
+    println!(
+        "{:<4} {:<4} {:<19}  {:<24} Verdict",
+        "Day", "Part", "Submitted", "Answer"
+    );
+    for attempt in &attempts {
+        println!(
+            "{:<4} {:<4} {:<19}  {:<24} {}",
+            format!("{:02}", attempt.day),
+            attempt.part,
+            history::timestamp(attempt.time),
+            attempt.answer.to_string(),
+            attempt.verdict
+        );
+    }
+    let mut parts: Vec<(u8, u8)> = attempts.iter().map(|a| (a.day, a.part)).collect();
+    parts.sort();
+    parts.dedup();
+    for (day, part) in parts {
+        if history
+            .attempts(day, part)
+            .any(|attempt| attempt.verdict == Verdict::Correct)
+        {
+            continue;
+        }
+        let bound = |bound: Option<i128>| bound.map_or("?".to_string(), |n| n.to_string());
+        let (low, high) = history.bounds(day, part);
+        if low.is_some() || high.is_some() {
+            println!(
+                "Day {day:02} part {part}: unsolved, between {} and {}",
+                bound(low),
+                bound(high)
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Serves every cached input and recorded answer until interrupted.
+fn serve(options: &Options) -> Result<(), Error> {
+    let mut site = Site::default();
+    let inputs = Cache::new("inputs", None);
+    for year in registry::years() {
+        let answers = Answers::load(&Answers::path(answers::DIR, year))?;
+        for entry in registry::days(year) {
+            if let Ok(input) = inputs.read(year, entry.day) {
+                site.inputs.insert((year, entry.day), input);
+            }
+            let Some(recorded) = answers.days.get(&entry.day) else {
+                continue;
+            };
+            for part in [1, 2] {
+                if let Some(answer) = recorded.part(part) {
+                    site.answers.insert((year, entry.day, part), answer.clone());
+                }
+            }
+        }
+    }
+    let (inputs, answers) = (site.inputs.len(), site.answers.len());
+    let server = MockServer::start(site, ("127.0.0.1", options.port.unwrap_or(0)))
+        .map_err(Error::io("127.0.0.1"))?;
+    println!(
+        "serving {inputs} input(s) and {answers} answer(s) at {}",
+        server.url()
+    );
+    server.wait();
+    Ok(())
+}
+
+fn new(year: u16, day: u8) -> Result<(), Error> {
+    if registry::find(year, day).is_some() {
+        return Err(Error::Input(format!("{year} day {day:02} already exists")));
+    }
+    for path in scaffold::create(Path::new("."), year, day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+pub fn main(args: &[String]) -> ExitCode {
+    match Command::try_from(args) {
+        Ok(Command::List { year }) => list(year),
+        Ok(Command::Solve {
+            action,
+            year,
+            days,
+            parts,
+            options,
+        }) => match execute(action, year, &days, &parts, &options) {
+            Ok(0) => ExitCode::SUCCESS,
+            Ok(failures) => {
+                eprintln!("{failures} part(s) failed");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Bench {
+            year,
+            days,
+            parts,
+            options,
+        }) => match bench(year, &days, &parts, &options) {
+            Ok(0) => ExitCode::SUCCESS,
+            Ok(regressions) => {
+                eprintln!("{regressions} phase(s) regressed");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Compare {
+            year,
+            days,
+            options,
+        }) => match compare(year, &days, &options) {
+            Ok(0) => ExitCode::SUCCESS,
+            Ok(divergences) => {
+                eprintln!("{divergences} part(s) diverged");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Render { year, day, options }) => match render(year, day, &options) {
+            Ok(count) => {
+                println!(
+                    "wrote {count} frame(s) to {}",
+                    options.out.unwrap().display()
+                );
+                ExitCode::SUCCESS
+            }
+            Err(Error::Unimplemented) => {
+                eprintln!("{year} day {day:02} has no simulation to render");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::View { year, day, options }) => match view(year, day, &options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(Error::Unimplemented) => {
+                eprintln!("{year} day {day:02} has no simulation to view");
+                ExitCode::FAILURE
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Submit {
+            year,
+            day,
+            part,
+            options,
+        }) => match submit(year, day, part, &options) {
+            Ok(verdict) => {
+                println!("{verdict}");
+                match verdict {
+                    Verdict::Correct => ExitCode::SUCCESS,
+                    _ => ExitCode::FAILURE,
+                }
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::Serve { options }) => match serve(&options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::History { year, day }) => match show_history(year, day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Command::New { year, day }) => match new(year, day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{Error, Result},
+    fixtures,
+};
+
+/// The day module every new day starts from, with `DayNN`, `YEAR` and `DAY` to fill in.
+const TEMPLATE: &str = include_str!("day/mod.rs");
+
+const FIXTURE: &str = "\
+# Expected answers for example.txt, such as
+# part1 = 0
+# part2 = 0
+";
+
+/// Creates the module and example fixture for a day under `root` and registers the day in
+/// `lib.rs`, the year's module, the registry and the example tests, returning every file written.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let src = root.join("src");
+    let module = src.join(format!("y{year}")).join(format!("day{:02}", day));
+    let fixture = fixtures::day_dir(root.join(fixtures::DIR), year, day);
+    for path in [&module, &fixture] {
+        if path.exists() {
+            return Err(Error::Input(format!("{} already exists", path.display())));
+        }
+    }
+
+    let name = format!("Day{:02}", day);
+    let code = TEMPLATE
+        .replace("DayNN", &name)
+        .replace("aocd(YEAR, DAY)", &format!("aocd({year}, {day})"));
+    let mut written = vec![
+        write(&module.join("mod.rs"), &code)?,
+        write(&fixture.join("example.txt"), "")?,
+        write(&fixture.join("example.toml"), FIXTURE)?,
+    ];
+
+    let year_mod = src.join(format!("y{year}")).join("mod.rs");
+    let contents = fs::read_to_string(&year_mod).unwrap_or_default();
+    let contents = insert_mod(&contents, &format!("day{:02}", day));
+    written.push(write(&year_mod, &contents)?);
+
+    let lib = src.join("lib.rs");
+    let contents = fs::read_to_string(&lib).map_err(Error::io(&lib))?;
+    if !contents
+        .lines()
+        .any(|line| line == format!("pub mod y{year};"))
+    {
+        let contents = insert_mod(&contents, &format!("y{year}"));
+        written.push(write(&lib, &contents)?);
+    }
+
+    let registry = src.join("registry.rs");
+    let contents = fs::read_to_string(&registry).map_err(Error::io(&registry))?;
+    let entry = format!("day!({year}, {day}, y{year}::day{:02}::{name}),", day);
+    let contents = insert_day(&contents, year, day, &entry).ok_or(Error::Input(format!(
+        "no DAYS table in {}",
+        registry.display()
+    )))?;
+    written.push(write(&registry, &contents)?);
+
+    let examples = root.join("tests").join("examples.rs");
+    let contents = fs::read_to_string(&examples).map_err(Error::io(&examples))?;
+    let contents = insert_example(&contents, year, day).ok_or(Error::Input(format!(
+        "no examples! list in {}",
+        examples.display()
+    )))?;
+    written.push(write(&examples, &contents)?);
+    Ok(written)
+}
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(Error::io(parent))?;
+    }
+    fs::write(path, contents).map_err(Error::io(path))?;
+    Ok(path.to_path_buf())
+}
+
+/// Adds `pub mod <name>;` among the other module declarations, keeping them sorted.
+fn insert_mod(contents: &str, name: &str) -> String {
+    let declaration = format!("pub mod {name};");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let at = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > declaration.as_str())
+        .or_else(|| {
+            let last = lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod "))?;
+            Some(last + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(at, &declaration);
+    lines.join("\n") + "\n"
+}
+
+/// Adds an entry to the registry's `DAYS` table, keeping it ordered by year and day.
+fn insert_day(contents: &str, year: u16, day: u8, entry: &str) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))?;
+    let end = start + lines[start..].iter().position(|line| *line == "];")?;
+    let key = |line: &str| -> Option<(u16, u8)> {
+        let (year, rest) = line.trim().strip_prefix("day!(")?.split_once(", ")?;
+        let (day, _) = rest.split_once(", ")?;
+        Some((year.parse().ok()?, day.parse().ok()?))
+    };
+    let at = (start + 1..end)
+        .find(|&i| key(lines[i]).is_some_and(|key| key > (year, day)))
+        .unwrap_or(end);
+    let line = format!("    {entry}");
+    lines.insert(at, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds a test to the `examples!` list, under the day's year, keeping years and days in order.
+fn insert_example(contents: &str, year: u16, day: u8) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    let start = lines.iter().position(|line| line == "examples! {")?;
+    let end = start + lines[start..].iter().position(|line| line == "}")?;
+    let header = |line: &str| -> Option<u16> {
+        let (module, rest) = line.strip_prefix("    ")?.split_once(": ")?;
+        module.strip_prefix('y')?;
+        rest.strip_suffix(" {")?.parse().ok()
+    };
+    let entry = format!("        day{:02}: {day},", day);
+    let Some(block) = (start + 1..end).find(|&i| header(&lines[i]) == Some(year)) else {
+        let at = (start + 1..end)
+            .find(|&i| header(&lines[i]).is_some_and(|other| other > year))
+            .unwrap_or(end);
+        let block = [
+            format!("    y{year}: {year} {{"),
+            entry,
+            "    }".to_string(),
+        ];
+        lines.splice(at..at, block);
+        return Some(lines.join("\n") + "\n");
+    };
+    let close = block + lines[block..].iter().position(|line| line == "    }")?;
+    let key = |line: &str| -> Option<u8> {
+        let (_, day) = line.trim().strip_suffix(',')?.split_once(": ")?;
+        day.parse().ok()
+    };
+    let at = (block + 1..close)
+        .find(|&i| key(&lines[i]).is_some_and(|other| other > day))
+        .unwrap_or(close);
+    lines.insert(at, entry);
+    Some(lines.join("\n") + "\n")
+}
@@ -0,0 +1,81 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    render::Frames,
+};
+
+/// Named puzzle parameters, such as grid sizes that differ between the examples and real inputs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T> {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| Error::Parse(format!("invalid value for {key}: {value}"))),
+            None => Ok(default),
+        }
+    }
+}
+
+pub trait Solution {
+    type Model;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Model>;
+
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Model> {
+        Self::parse(input)
+    }
+
+    fn part1(model: &Self::Model) -> Result<Self::Answer1>;
+
+    fn part2(model: &Self::Model) -> Result<Self::Answer2>;
+
+    /// Successive grid states of the puzzle's simulation, for days that have one to watch.
+    fn frames(_model: &Self::Model, _params: &Params) -> Result<Frames> {
+        Err(Error::Unimplemented)
+    }
+
+    fn solve1(input: &str) -> Result<Self::Answer1> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solve2(input: &str) -> Result<Self::Answer2> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+/// A parsed model with its solution type erased, so both parts share one parse.
+pub trait Parsed: Sync {
+    fn part(&self, part: u8) -> Result<Answer>;
+}
+
+struct Model<S: Solution>(S::Model);
+
+impl<S: Solution> Parsed for Model<S>
+where
+    S::Model: Sync,
+{
+    fn part(&self, part: u8) -> Result<Answer> {
+        match part {
+            1 => S::part1(&self.0).map(Into::into),
+            _ => S::part2(&self.0).map(Into::into),
+        }
+    }
+}
+
+pub fn parse<S: Solution + 'static>(input: &str, params: &Params) -> Result<Box<dyn Parsed>>
+where
+    S::Model: Sync + 'static,
+{
+    Ok(Box::new(Model::<S>(S::parse_with(input, params)?)))
+}
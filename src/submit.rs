@@ -0,0 +1,156 @@
+use std::{env, fmt::Display, fs, time::Duration};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    utils::parse,
+};
+
+pub const SITE: &str = "https://adventofcode.com";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Another answer was given too recently; the site says how long is left to wait.
+    RateLimited(Option<Duration>),
+    /// The part is already solved, or part 2 was submitted before part 1.
+    WrongLevel,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, {}s left", wait.as_secs()),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the site returns for a submitted answer.
+pub fn verdict(html: &str) -> Result<Verdict> {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    if article.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if article.contains("That's not the right answer") {
+        Ok(match article {
+            _ if article.contains("too high") => Verdict::TooHigh,
+            _ if article.contains("too low") => Verdict::TooLow,
+            _ => Verdict::Wrong,
+        })
+    } else if article.contains("You gave an answer too recently") {
+        let wait = article
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait);
+        let seconds = wait.and_then(|wait| match parse::integers::<u64>(wait).ok()?[..] {
+            [m, s] => Some(m * 60 + s),
+            [s] if wait.ends_with('s') => Some(s),
+            [m] => Some(m * 60),
+            _ => None,
+        });
+        Ok(Verdict::RateLimited(seconds.map(Duration::from_secs)))
+    } else if article.contains("Did you already complete it") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(Error::Input(format!("unexpected response: {article}")))
+    }
+}
+
+/// Where puzzle inputs come from and answers go to.
+pub trait Submitter: Sync {
+    fn input(&self, year: u16, day: u8) -> Result<String>;
+
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<Verdict>;
+}
+
+/// The session token aocd uses, from `AOC_SESSION`, `AOC_TOKEN` or `~/.config/aocd/token`.
+fn session() -> Option<String> {
+    if let Ok(token) = env::var("AOC_SESSION").or_else(|_| env::var("AOC_TOKEN")) {
+        return Some(token.trim().to_string());
+    }
+    let path = env::var("AOC_TOKEN_PATH")
+        .or_else(|_| env::var("HOME").map(|home| format!("{home}/.config/aocd/token")))
+        .ok()?;
+    fs::read_to_string(path)
+        .ok()
+        .map(|token| token.trim().to_string())
+}
+
+/// Escapes an answer for a form body.
+fn encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// The site itself, or anything at `url` that answers like it.
+pub struct Http {
+    pub url: String,
+    session: Option<String>,
+}
+
+impl Http {
+    pub fn new(url: impl Into<String>) -> Http {
+        Http {
+            url: url.into().trim_end_matches('/').to_string(),
+            session: session(),
+        }
+    }
+
+    fn send(&self, request: minreq::Request) -> Result<String> {
+        let request = match &self.session {
+            Some(session) => request.with_header("Cookie", format!("session={session}")),
+            None if self.url == SITE => {
+                return Err(Error::Input(
+                    "no session token in AOC_SESSION or ~/.config/aocd/token".to_string(),
+                ))
+            }
+            None => request,
+        };
+        let response = request
+            .with_timeout(30)
+            .send()
+            .map_err(|e| Error::Input(format!("{}: {e}", self.url)))?;
+        let body = response
+            .as_str()
+            .map_err(|e| Error::Input(format!("{}: {e}", self.url)))?;
+        match response.status_code {
+            200 => Ok(body.to_string()),
+            code => Err(Error::Input(format!(
+                "{} responded {code} {}",
+                self.url, response.reason_phrase
+            ))),
+        }
+    }
+}
+
+impl Submitter for Http {
+    fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.send(minreq::get(format!("{}/{year}/day/{day}/input", self.url)))
+    }
+
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<Verdict> {
+        let body = format!("level={part}&answer={}", encode(&answer.to_string()));
+        let request = minreq::post(format!("{}/{year}/day/{day}/answer", self.url))
+            .with_header("Content-Type", "application/x-www-form-urlencoded")
+            .with_body(body);
+        verdict(&self.send(request)?)
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::error::{Error, Result};
+
+/// The subset of TOML used by our fixture and answer files: `[table]` headers,
+/// `key = value` pairs and string, integer or boolean values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Integer(i128),
+    Boolean(bool),
+    Table(Table),
+}
+
+pub type Table = BTreeMap<String, Value>;
+
+impl Value {
+    pub fn as_table(&self) -> Option<&Table> {
+        match self {
+            Value::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::String(s) => write!(f, "{s}"),
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Boolean(b) => write!(f, "{b}"),
+            Value::Table(_) => write!(f, "<table>"),
+        }
+    }
+}
+
+fn error(line: usize, message: &str) -> Error {
+    Error::Parse(format!("toml line {}: {}", line + 1, message))
+}
+
+fn read_string(s: &str, line: usize) -> Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or(error(line, "unterminated string"))?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            _ => return Err(error(line, "invalid escape sequence")),
+        }
+    }
+    Ok(out)
+}
+
+fn read_value(s: &str, line: usize) -> Result<Value> {
+    match s {
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        _ if s.starts_with('"') => Ok(Value::String(read_string(s, line)?)),
+        _ => s
+            .replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| error(line, &format!("invalid value: {s}"))),
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => escaped = !escaped,
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => escaped = false,
+        }
+    }
+    line
+}
+
+pub fn parse(input: &str) -> Result<Table> {
+    let mut root = Table::new();
+    let mut current: Option<String> = None;
+    for (i, line) in input.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or(error(i, "unterminated table header"))?
+                .trim();
+            if root
+                .insert(name.to_string(), Value::Table(Table::new()))
+                .is_some()
+            {
+                return Err(error(i, "duplicate table"));
+            }
+            current = Some(name.to_string());
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(error(i, "expected key = value"))?;
+        let key = key.trim().trim_matches('"').to_string();
+        let value = read_value(value.trim(), i)?;
+        let table = match &current {
+            Some(name) => match root.get_mut(name) {
+                Some(Value::Table(table)) => table,
+                _ => unreachable!(),
+            },
+            None => &mut root,
+        };
+        table.insert(key, value);
+    }
+    Ok(root)
+}
+
+fn write_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => {
+            let escaped = s
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\t', "\\t");
+            format!("\"{escaped}\"")
+        }
+        _ => value.to_string(),
+    }
+}
+
+pub fn to_string(root: &Table) -> String {
+    let mut out = String::new();
+    for (key, value) in root.iter().filter(|(_, v)| v.as_table().is_none()) {
+        out += &format!("{} = {}\n", key, write_value(value));
+    }
+    for (name, table) in root.iter().filter_map(|(k, v)| Some((k, v.as_table()?))) {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out += &format!("[{name}]\n");
+        for (key, value) in table.iter() {
+            out += &format!("{} = {}\n", key, write_value(value));
+        }
+    }
+    out
+}
@@ -0,0 +1,111 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence `start, step(start), step(step(start)), ...` starts repeating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    /// How many states come before the first one that repeats.
+    pub tail: usize,
+    /// How many states there are in the repeating part.
+    pub period: usize,
+    /// The first state that repeats, which is state `tail`.
+    pub state: T,
+}
+
+impl<T> Cycle<T> {
+    /// The index among the first `tail + period` states of the state at index `n`.
+    pub fn index(&self, n: usize) -> usize {
+        match n.checked_sub(self.tail) {
+            Some(into) => self.tail + into % self.period,
+            None => n,
+        }
+    }
+}
+
+/// Brent's algorithm, which needs only to compare states and keeps two of them at a time.
+pub fn brent<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle<T> {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start, |state, _| step(&state));
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+    Cycle {
+        tail,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Floyd's tortoise and hare. Takes more steps than [`brent`] but is the better known of the two.
+pub fn floyd<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle<T> {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let once = step(&hare);
+        hare = step(&once);
+    }
+
+    let mut tortoise = start;
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    let mut hare = step(&tortoise);
+    let mut period = 1;
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle {
+        tail,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Remembers every state seen, so each is stepped from only once. Quicker than [`brent`] when
+/// states are cheap to hash and stepping is dear.
+pub fn hashed<T: Hash + Eq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle<T> {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    let mut index = 0;
+    loop {
+        if let Some(&tail) = seen.get(&state) {
+            return Cycle {
+                tail,
+                period: index - tail,
+                state,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state, index);
+        state = next;
+        index += 1;
+    }
+}
+
+/// The state at index `n`, taking at most a few times `tail + period` steps however large `n`
+/// is.
+pub fn nth<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+    let cycle = brent(start.clone(), &mut step);
+    (0..cycle.index(n)).fold(start, |state, _| step(&state))
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    utils::{xy, XY},
+};
+
+/// A step on a grid whose y axis points down, written as `^`, `>`, `v` or `<`.
+#[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+impl From<Direction> for XY {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => xy(0, -1),
+            Direction::Right => xy(1, 0),
+            Direction::Down => xy(0, 1),
+            Direction::Left => xy(-1, 0),
+        }
+    }
+}
+
+impl TryFrom<XY> for Direction {
+    type Error = Error;
+
+    fn try_from(step: XY) -> Result<Self> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| XY::from(direction) == step)
+            .ok_or(Error::Parse(format!("not a unit step: {step:?}")))
+    }
+}
+
+impl From<Direction> for char {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            _ => Err(Error::Parse(format!("not a direction: {c:?}"))),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl std::ops::Add<Direction> for XY {
+    type Output = XY;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + XY::from(rhs)
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::{Error, ParseError},
+    utils::{xy, XY},
+};
+
+/// A rectangular grid stored row by row, addressed by `XY` with the origin at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: XY) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    fn offset(&self, position: XY) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    pub fn get(&self, position: XY) -> Option<&T> {
+        self.offset(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: XY) -> Option<&mut T> {
+        self.offset(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the cell at `position`, returning the previous value if it was in bounds.
+    pub fn set(&mut self, position: XY, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = XY> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| xy((i % width) as i32, (i / width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (XY, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The in-bounds cardinal neighbours of `position`.
+    pub fn neighbors(&self, position: XY) -> impl Iterator<Item = XY> + '_ {
+        position
+            .neighbors()
+            .into_iter()
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    pub fn neighbors8(&self, position: XY) -> impl Iterator<Item = XY> + '_ {
+        position
+            .neighbors8()
+            .into_iter()
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// Panics when `position` is out of bounds; use [`Grid::get`] otherwise.
+impl<T> Index<XY> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: XY) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<XY> for Grid<T> {
+    fn index_mut(&mut self, position: XY) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    pub fn find(&self, value: &T) -> Option<XY> {
+        self.iter()
+            .find(|&(_, cell)| cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = XY> + 'a {
+        self.iter()
+            .filter(move |&(_, cell)| cell == value)
+            .map(|(position, _)| position)
+    }
+}
+
+/// Parses one cell per character; every line must have the same width.
+impl<T: TryFrom<char>> TryFrom<&str> for Grid<T> {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Grid::parse(input, "a grid cell")
+    }
+}
+
+impl<T: TryFrom<char>> Grid<T> {
+    /// As [`Grid::try_from`], naming what a cell should have been when one does not convert.
+    pub fn parse(input: &str, expected: &str) -> Result<Self, Error> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let mut count = 0;
+            for (i, c) in line.char_indices() {
+                let cell = T::try_from(c)
+                    .map_err(|_| ParseError::new(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+                count += 1;
+            }
+            match width {
+                Some(width) if width != count => {
+                    let expected = format!("a row of {width} cells");
+                    return Err(ParseError::new(input, line, expected).into());
+                }
+                _ => width = Some(count),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The signed integer types the helpers here work over.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `self * rhs` modulo a positive `modulus`, in `0..modulus`, without overflowing.
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self;
+
+    /// `self` modulo a positive `modulus`, in `0..modulus`.
+    fn modulo(self, modulus: Self) -> Self {
+        let r = self % modulus;
+        if r < Self::ZERO {
+            r + modulus
+        } else {
+            r
+        }
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! integer_impl {
+    ($t:ty, $wide:ty) => {
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                (self as $wide * rhs as $wide).rem_euclid(modulus as $wide) as $t
+            }
+        }
+    };
+}
+
+integer_impl!(i32, i64);
+integer_impl!(i64, i128);
+
+/// There is nothing wider to multiply in, so `i128` doubles and adds instead.
+impl Integer for i128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let add = |a: i128, b: i128| {
+            if a >= modulus - b {
+                a - (modulus - b)
+            } else {
+                a + b
+            }
+        };
+        let (mut a, mut b) = (self.rem_euclid(modulus), rhs.rem_euclid(modulus));
+        let mut product = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                product = add(product, a);
+            }
+            a = add(a, a);
+            b >>= 1;
+        }
+        product
+    }
+}
+
+/// Always non-negative, and zero only when both are.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x` congruent to 1, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.modulo(modulus), modulus);
+    (g == T::ONE).then(|| x.modulo(modulus))
+}
+
+/// The Chinese Remainder Theorem: the least non-negative `x` congruent to every `residue` modulo
+/// its `modulus`, along with the lcm of the moduli that every other solution differs from it by.
+/// The moduli need not be coprime, but then the residues have to agree.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+    for (residue, modulus) in congruences {
+        let (x, m) = solution;
+        let g = gcd(m, modulus);
+        let difference = residue.modulo(modulus) - x.modulo(modulus);
+        if difference % g != T::ZERO {
+            return None;
+        }
+        let reduced = modulus / g;
+        let t = (difference / g).mul_mod(mod_inverse(m / g, reduced)?, reduced);
+        let l = m * reduced;
+        solution = ((x + m * t).modulo(l), l);
+    }
+    Some(solution)
+}
+
+/// An exact fraction, kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    pub numerator: T,
+    pub denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// `None` when the denominator is zero.
+    pub fn new(numerator: T, denominator: T) -> Option<Rational<T>> {
+        if denominator == T::ZERO {
+            return None;
+        }
+        let sign = if denominator < T::ZERO {
+            -T::ONE
+        } else {
+            T::ONE
+        };
+        let g = gcd(numerator, denominator);
+        Some(Rational {
+            numerator: sign * numerator / g,
+            denominator: sign * denominator / g,
+        })
+    }
+
+    pub fn integer(&self) -> Option<T> {
+        (self.denominator == T::ONE).then_some(self.numerator)
+    }
+}
+
+impl<T: Integer> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.integer() {
+            Some(n) => write!(f, "{n}"),
+            None => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+/// Solves `matrix * [x, y] == rhs` exactly by Cramer's rule, or `None` if the matrix is singular.
+pub fn solve2<T: Integer>(matrix: [[T; 2]; 2], rhs: [T; 2]) -> Option<[Rational<T>; 2]> {
+    let [[a, b], [c, d]] = matrix;
+    let [e, f] = rhs;
+    let determinant = a * d - b * c;
+    Some([
+        Rational::new(e * d - b * f, determinant)?,
+        Rational::new(a * f - e * c, determinant)?,
+    ])
+}
@@ -0,0 +1,147 @@
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod search;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use memo::Memo;
+
+pub fn xy(x: i32, y: i32) -> XY {
+    XY::new(x, y)
+}
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct XY<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> XY<T> {
+    pub fn new(x: T, y: T) -> XY<T> {
+        XY { x, y }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> XY<T> {
+    /// Quarter turn clockwise, with y pointing down.
+    pub fn rotate_right(self) -> XY<T> {
+        XY::new(-self.y, self.x)
+    }
+
+    pub fn rotate_left(self) -> XY<T> {
+        XY::new(self.y, -self.x)
+    }
+}
+
+macro_rules! xy_impl {
+    ($t:ty) => {
+        impl XY<$t> {
+            pub fn neighbors(&self) -> [XY<$t>; 4] {
+                [
+                    XY::new(self.x + 1, self.y),
+                    XY::new(self.x - 1, self.y),
+                    XY::new(self.x, self.y + 1),
+                    XY::new(self.x, self.y - 1),
+                ]
+            }
+
+            /// The cardinal and diagonal neighbours, clockwise from the top left.
+            pub fn neighbors8(&self) -> [XY<$t>; 8] {
+                [
+                    (-1, -1),
+                    (0, -1),
+                    (1, -1),
+                    (1, 0),
+                    (1, 1),
+                    (0, 1),
+                    (-1, 1),
+                    (-1, 0),
+                ]
+                .map(|(x, y)| XY::new(self.x + x, self.y + y))
+            }
+
+            pub fn manhattan(&self, other: XY<$t>) -> $t {
+                (other.x - self.x).abs() + (other.y - self.y).abs()
+            }
+
+            pub fn chebyshev(&self, other: XY<$t>) -> $t {
+                (other.x - self.x).abs().max((other.y - self.y).abs())
+            }
+        }
+    };
+}
+
+xy_impl!(i32);
+xy_impl!(i64);
+
+pub fn cardinals() -> [XY; 4] {
+    [XY::new(1, 0), XY::new(-1, 0), XY::new(0, 1), XY::new(0, -1)]
+}
+
+impl<T: Add<Output = T>> Add for XY<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        XY {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for XY<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        XY {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for XY<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        XY {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for XY<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        XY {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl From<XY> for XY<i64> {
+    fn from(value: XY) -> Self {
+        XY {
+            x: value.x as i64,
+            y: value.y as i64,
+        }
+    }
+}
+
+impl From<(usize, usize)> for XY {
+    fn from(value: (usize, usize)) -> Self {
+        XY {
+            x: value.0 as i32,
+            y: value.1 as i32,
+        }
+    }
+}
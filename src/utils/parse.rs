@@ -0,0 +1,63 @@
+use std::{str::FromStr, sync::LazyLock};
+
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+
+static INTEGER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"-?\d+").unwrap());
+
+static BLANK_LINE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\r?\n\r?\n").unwrap());
+
+/// Every integer in `text`, taking a `-` directly before the digits as its sign.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    INTEGER
+        .find_iter(text)
+        .map(|found| error::number(text, found.as_str()))
+        .collect()
+}
+
+/// Exactly `N` integers from `text`, or an error expecting `format`.
+pub fn exactly<T: FromStr, const N: usize>(text: &str, format: &str) -> Result<[T; N], ParseError> {
+    integers(text)?
+        .try_into()
+        .map_err(|_| ParseError::new(text, text, format))
+}
+
+/// The runs of lines separated by blank lines, with either line ending.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    BLANK_LINE
+        .split(input)
+        .map(|block| block.trim_matches(['\r', '\n']))
+        .filter(|block| !block.is_empty())
+}
+
+/// An error at the end of `input`, for something it never contained.
+pub fn missing(input: &str, expected: impl Into<String>) -> ParseError {
+    ParseError::new(input, &input[input.len()..], expected)
+}
+
+/// The text before and after the first blank line.
+pub fn two_blocks(input: &str) -> Result<(&str, &str), ParseError> {
+    BLANK_LINE
+        .find(input)
+        .map(|blank| (&input[..blank.start()], &input[blank.end()..]))
+        .ok_or_else(|| missing(input, "a blank line"))
+}
+
+/// Splits `line` around its first `separator`.
+pub fn pair<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(separator)
+        .ok_or_else(|| ParseError::new(line, line, format!("`a{separator}b`")))
+}
+
+/// Splits a `key: value` line.
+pub fn key_value(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(": ")
+        .ok_or_else(|| ParseError::new(line, line, "`key: value`"))
+}
+
+/// Two numbers either side of `separator`, such as `47|53`.
+pub fn number_pair<T: FromStr>(line: &str, separator: &str) -> Result<(T, T), ParseError> {
+    let (a, b) = pair(line, separator)?;
+    Ok((error::number(line, a)?, error::number(line, b)?))
+}
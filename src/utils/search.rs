@@ -0,0 +1,208 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A heap entry ordered so that `BinaryHeap` pops the cheapest state first.
+struct State<N, C> {
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+/// Costs of every state reached by a search, with all of their optimal predecessors.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    pub start: N,
+    pub dist: HashMap<N, C>,
+    pub prev: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Paths<N, C> {
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// One optimal path from the start to `goal`, both included.
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        self.dist.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(prior) = self.prev.get(path.last().unwrap()).and_then(|p| p.first()) {
+            path.push(prior.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every optimal path from the start to `goal`.
+    pub fn all(&self, goal: &N) -> Vec<Vec<N>> {
+        if !self.dist.contains_key(goal) {
+            return Vec::new();
+        }
+        if *goal == self.start {
+            return vec![vec![goal.clone()]];
+        }
+        let mut paths = Vec::new();
+        for prior in self.prev.get(goal).into_iter().flatten() {
+            for mut path in self.all(prior) {
+                path.push(goal.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// The states lying on any optimal path to the cheapest of `goals`.
+    pub fn nodes(&self, goals: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let goals: Vec<(C, N)> = goals
+            .into_iter()
+            .filter_map(|goal| Some((self.cost(&goal)?, goal)))
+            .collect();
+        let Some(best) = goals.iter().map(|(cost, _)| *cost).min() else {
+            return HashSet::new();
+        };
+        let mut open: Vec<N> = goals
+            .into_iter()
+            .filter(|(cost, _)| *cost == best)
+            .map(|(_, goal)| goal)
+            .collect();
+        let mut seen = HashSet::new();
+        while let Some(node) = open.pop() {
+            if seen.insert(node.clone()) {
+                open.extend(self.prev.get(&node).into_iter().flatten().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Shortest paths from `start`, stopping once every state cheaper than the first goal reached
+/// has been settled. Goal states are not expanded. Every equally cheap predecessor is kept.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = HashMap::from([(start.clone(), C::default())]);
+    let mut prev = HashMap::<N, Vec<N>>::new();
+    let mut open = BinaryHeap::from([State {
+        cost: C::default(),
+        node: start.clone(),
+    }]);
+    let mut best = None;
+    while let Some(State { cost, node }) = open.pop() {
+        if dist.get(&node).is_some_and(|&d| d < cost) {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if goal(&node) {
+            best.get_or_insert(cost);
+            continue;
+        }
+        for (next, step) in neighbors(&node) {
+            let alt = cost + step;
+            match dist.get(&next) {
+                Some(&d) if alt > d => (),
+                Some(&d) if alt == d => prev.entry(next).or_default().push(node.clone()),
+                _ => {
+                    dist.insert(next.clone(), alt);
+                    prev.insert(next.clone(), vec![node.clone()]);
+                    open.push(State {
+                        cost: alt,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+    Paths { start, dist, prev }
+}
+
+/// Dijkstra where every step costs one.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    dijkstra(
+        start,
+        |node| neighbors(node).into_iter().map(|next| (next, 1)),
+        goal,
+    )
+}
+
+/// A cheapest path from `start` to a goal and its cost. Since states are closed once expanded,
+/// the heuristic must be consistent: it may fall by no more than the cost of any step.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = HashMap::from([(start.clone(), C::default())]);
+    let mut prev = HashMap::<N, Vec<N>>::new();
+    let mut open = BinaryHeap::from([State {
+        cost: heuristic(&start),
+        node: start.clone(),
+    }]);
+    let mut closed = HashSet::new();
+    while let Some(State { node, .. }) = open.pop() {
+        if !closed.insert(node.clone()) {
+            continue;
+        }
+        let cost = dist[&node];
+        if goal(&node) {
+            let paths = Paths { start, dist, prev };
+            return Some((paths.path(&node)?, cost));
+        }
+        for (next, step) in neighbors(&node) {
+            let alt = cost + step;
+            if dist.get(&next).is_none_or(|&d| alt < d) {
+                dist.insert(next.clone(), alt);
+                prev.insert(next.clone(), vec![node.clone()]);
+                open.push(State {
+                    cost: alt + heuristic(&next),
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
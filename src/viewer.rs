@@ -0,0 +1,107 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{render::Frames, utils::Grid};
+
+/// Frames pulled from a simulation as they are first needed, and kept so they can be revisited.
+pub struct Recording {
+    frames: Frames,
+    states: Vec<Grid<char>>,
+    complete: bool,
+}
+
+impl Recording {
+    pub fn new(frames: Frames) -> Recording {
+        Recording {
+            frames,
+            states: Vec::new(),
+            complete: false,
+        }
+    }
+
+    pub fn get(&mut self, index: usize) -> Option<&Grid<char>> {
+        while self.states.len() <= index && !self.complete {
+            match self.frames.next() {
+                Some(frame) => self.states.push(frame),
+                None => self.complete = true,
+            }
+        }
+        self.states.get(index)
+    }
+
+    /// The number of frames, once the simulation has run to its end.
+    pub fn total(&self) -> Option<usize> {
+        self.complete.then_some(self.states.len())
+    }
+
+    /// The index of the frame closest to `index` that exists.
+    fn clamp(&mut self, index: usize) -> usize {
+        match self.get(index) {
+            Some(_) => index,
+            None => self.states.len().saturating_sub(1),
+        }
+    }
+}
+
+enum Command {
+    Next(usize),
+    Previous(usize),
+    Jump(usize),
+    Quit,
+}
+
+fn read_command(line: &str) -> Option<Command> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words[..] {
+        [] | ["n"] => Some(Command::Next(1)),
+        ["n", n] => n.parse().ok().map(Command::Next),
+        ["p"] => Some(Command::Previous(1)),
+        ["p", n] => n.parse().ok().map(Command::Previous),
+        ["q"] => Some(Command::Quit),
+        ["g", step] | [step] => step.parse().ok().map(Command::Jump),
+        _ => None,
+    }
+}
+
+/// Steps through the frames, numbering the first one `first` and starting at step `start`.
+/// Reads one command per line: `n [N]` or an empty line to go forward, `p [N]` to go back,
+/// `g N` or just `N` to jump to a step, and `q` to quit.
+pub fn view(
+    frames: Frames,
+    first: usize,
+    start: usize,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut recording = Recording::new(frames);
+    if recording.get(0).is_none() {
+        return writeln!(out, "no frames to view");
+    }
+    let mut current = recording.clamp(start.saturating_sub(first));
+    let mut message = "";
+    loop {
+        let frame = recording.get(current).unwrap();
+        write!(out, "\x1b[2J\x1b[H{frame}")?;
+        let last = recording
+            .total()
+            .map_or(String::new(), |len| format!(" of {}", first + len - 1));
+        writeln!(out, "step {}{last}{message}", first + current)?;
+        write!(out, "[n]ext [p]rev [g N] jump [q]uit > ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        message = "";
+        let target = match read_command(&line) {
+            Some(Command::Next(n)) => current + n,
+            Some(Command::Previous(n)) => current.saturating_sub(n),
+            Some(Command::Jump(step)) => step.saturating_sub(first),
+            Some(Command::Quit) => return Ok(()),
+            None => {
+                message = " (unknown command)";
+                current
+            }
+        };
+        current = recording.clamp(target);
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use aocd::*;
+
+use crate::{error::Result, solution::Solution, utils::parse};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Model = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        input
+            .lines()
+            .map(|line| parse::number_pair(line, "   ").map_err(|e| e.within(input, line).into()))
+            .collect()
+    }
+
+    fn part1(data: &Self::Model) -> Result<Self::Answer1> {
+        let mut first: Vec<i64> = data.iter().map(|(first, _)| *first).collect();
+        first.sort();
+
+        let mut second: Vec<i64> = data.iter().map(|(_, second)| *second).collect();
+        second.sort();
+
+        let total: i64 = first
+            .iter()
+            .zip(second.iter())
+            .map(|(u, v)| (*u - *v).abs())
+            .sum();
+
+        Ok(total)
+    }
+
+    fn part2(data: &Self::Model) -> Result<Self::Answer2> {
+        let mut counts = HashMap::<i64, u64>::new();
+        for (_, second) in data.iter() {
+            let entry = counts.entry(*second).or_insert(0);
+            *entry += 1;
+        }
+
+        let mut score: u64 = 0;
+        for (first, _) in data.iter() {
+            let entry = counts.entry(*first).or_insert(0);
+            score += (*first as u64) * *entry;
+        }
+
+        Ok(score)
+    }
+}
+
+#[aocd(2024, 1)]
+pub fn solution1() {
+    submit!(1, Day01::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 1)]
+pub fn solution2() {
+    submit!(2, Day01::solve2(&input!()).unwrap());
+}
@@ -1,17 +1,24 @@
 use aocd::*;
 
-struct Level {
+use crate::{
+    error::{self, Error, Result},
+    solution::Solution,
+};
+
+pub struct Level {
     values: Vec<i64>,
 }
 
-impl From<&str> for Level {
-    fn from(s: &str) -> Self {
-        Level {
+impl TryFrom<&str> for Level {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(Level {
             values: s
                 .split(' ')
-                .map(|num| num.parse::<i64>().unwrap())
-                .collect(),
-        }
+                .map(|num| error::number(s, num))
+                .collect::<std::result::Result<_, _>>()?,
+        })
     }
 }
 
@@ -47,22 +54,38 @@ impl Level {
     }
 }
 
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Model = Vec<Level>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        input
+            .lines()
+            .map(|line| Level::try_from(line).map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part1(levels: &Self::Model) -> Result<Self::Answer1> {
+        Ok(levels.iter().map(|level| level.is_safe() as u64).sum())
+    }
+
+    fn part2(levels: &Self::Model) -> Result<Self::Answer2> {
+        Ok(levels
+            .iter()
+            .map(|level| level.is_partially_safe() as u64)
+            .sum())
+    }
+}
+
 #[aocd(2024, 2)]
 pub fn solution1() {
-    let safe_levels: u64 = input!()
-        .split('\n')
-        .map(|s| Level::from(s).is_safe() as u64)
-        .sum();
-
-    submit!(1, safe_levels);
+    submit!(1, Day02::solve1(&input!()).unwrap());
 }
 
 #[aocd(2024, 2)]
 pub fn solution2() {
-    let safe_levels: u64 = input!()
-        .split('\n')
-        .map(|s| Level::from(s).is_partially_safe() as u64)
-        .sum();
-
-    submit!(2, safe_levels);
+    submit!(2, Day02::solve2(&input!()).unwrap());
 }
@@ -0,0 +1,70 @@
+use std::sync::LazyLock;
+
+use aocd::*;
+use regex::Regex;
+
+use crate::{error::Result, solution::Solution};
+
+static MUL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap());
+
+static INSTRUCTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"mul\((\d{1,3},\d{1,3})\)|(do\(\))|(don't\(\))").unwrap());
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(input.to_string())
+    }
+
+    fn part1(data: &Self::Model) -> Result<Self::Answer1> {
+        let sum: u64 = MUL
+            .captures_iter(data.as_str())
+            .map(|c| {
+                let (_, [first, second]) = c.extract();
+                let first = first.parse::<u64>().unwrap();
+                let second = second.parse::<u64>().unwrap();
+                first * second
+            })
+            .sum();
+        Ok(sum)
+    }
+
+    fn part2(data: &Self::Model) -> Result<Self::Answer2> {
+        let mut score = 0;
+        INSTRUCTION
+            .captures_iter(data.as_str())
+            .fold(true, |mut enabled, capture| {
+                let (_, [extract]) = capture.extract();
+                if extract == "do()" {
+                    enabled = true;
+                } else if extract == "don't()" {
+                    enabled = false;
+                } else {
+                    let pair: Vec<u64> = extract
+                        .split(',')
+                        .map(|num| num.parse::<u64>().unwrap())
+                        .collect();
+                    let first = *pair.first().unwrap();
+                    let second = *pair.last().unwrap();
+                    score += (enabled as u64) * first * second;
+                }
+                enabled
+            });
+        Ok(score)
+    }
+}
+
+#[aocd(2024, 3)]
+pub fn solution1() {
+    submit!(1, Day03::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 3)]
+pub fn solution2() {
+    submit!(2, Day03::solve2(&input!()).unwrap());
+}
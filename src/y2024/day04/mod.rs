@@ -0,0 +1,61 @@
+use aocd::*;
+
+use crate::{
+    error::Result,
+    solution::Solution,
+    utils::{xy, Grid, XY},
+};
+
+fn get_word(grid: &Grid<char>, start: XY, direction: XY, length: i32) -> String {
+    (0..length)
+        .flat_map(|scale| grid.get(start + direction * scale))
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Model = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Grid::try_from(input)
+    }
+
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1> {
+        let mut count = 0;
+        for position in grid.positions() {
+            for u in -1..=1 {
+                for v in -1..=1 {
+                    if (u, v) != (0, 0) && get_word(grid, position, xy(u, v), 4) == *"XMAS" {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2> {
+        let mut count = 0;
+        for position in grid.positions() {
+            let first = get_word(grid, position, xy(1, 1), 3);
+            let second = get_word(grid, position + xy(2, 0), xy(-1, 1), 3);
+            if (first == *"MAS" || first == *"SAM") && (second == *"MAS" || second == *"SAM") {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[aocd(2024, 4)]
+pub fn solution1() {
+    submit!(1, Day04::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 4)]
+pub fn solution2() {
+    submit!(2, Day04::solve2(&input!()).unwrap());
+}
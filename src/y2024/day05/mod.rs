@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use aocd::*;
+
+use crate::{
+    error::{self, Result},
+    solution::Solution,
+    utils::parse,
+};
+
+type Rules = HashMap<i32, HashSet<i32>>;
+
+fn read_rules(input: &str, rules: &str) -> Result<Rules> {
+    let mut table = Rules::new();
+    for line in rules.lines() {
+        let (before, after) = parse::number_pair(line, "|").map_err(|e| e.within(input, line))?;
+        table.entry(before).or_default().insert(after);
+    }
+    Ok(table)
+}
+
+fn read_updates(input: &str, updates: &str) -> Result<Vec<Vec<i32>>> {
+    updates
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|num| Ok(error::number(line, num)?))
+                .collect::<Result<Vec<i32>>>()
+                .map_err(|e| e.within(input, line))
+        })
+        .collect()
+}
+
+fn is_valid_update(orders: &[i32], rules: &Rules) -> bool {
+    let n = orders.len();
+    orders.iter().take(n - 1).enumerate().all(|(i, order)| {
+        let after: HashSet<i32> = orders[(i + 1)..].iter().cloned().collect();
+        let allowed = rules.get(order).cloned().unwrap_or_default();
+        after.is_subset(&allowed)
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = (Rules, Vec<Vec<i32>>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let (rules, updates) = parse::two_blocks(input)?;
+        Ok((read_rules(input, rules)?, read_updates(input, updates)?))
+    }
+
+    fn part1((rules, updates): &Self::Model) -> Result<Self::Answer1> {
+        let score: i32 = updates
+            .iter()
+            .filter(|orders| is_valid_update(orders, rules))
+            .map(|orders| orders[orders.len() / 2])
+            .sum();
+        Ok(score)
+    }
+
+    fn part2((rules, updates): &Self::Model) -> Result<Self::Answer2> {
+        let score: i32 = updates
+            .iter()
+            .filter(|orders| !is_valid_update(orders, rules))
+            .cloned()
+            .map(|mut orders| {
+                let orders_set: HashSet<i32> = orders.iter().cloned().collect();
+                orders.sort_by_key(|order| {
+                    let allowed = rules.get(order).cloned().unwrap_or_default();
+                    orders_set.intersection(&allowed).count()
+                });
+                orders[orders.len() / 2]
+            })
+            .sum();
+        Ok(score)
+    }
+}
+
+#[aocd(2024, 5)]
+pub fn solution1() {
+    submit!(1, Day05::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 5)]
+pub fn solution2() {
+    submit!(2, Day05::solve2(&input!()).unwrap());
+}
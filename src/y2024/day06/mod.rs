@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use aocd::*;
+
+use crate::{
+    error::{Error, Result},
+    render::Frames,
+    solution::{Params, Solution},
+    utils::{cycle, parse, Direction, Grid, XY},
+};
+
+#[derive(Clone)]
+pub struct Lab {
+    grid: Grid<char>,
+    start: XY,
+}
+
+impl Lab {
+    fn new(input: &str) -> Result<Lab> {
+        let grid = Grid::try_from(input)?;
+        let start = grid
+            .find(&'^')
+            .ok_or_else(|| parse::missing(input, "a guard `^`"))?;
+        Ok(Lab { grid, start })
+    }
+
+    /// Where the guard is next, or `None` once they have left the lab. A guard walled in on all
+    /// four sides stays put, which counts as going round in circles.
+    fn step(&self, (position, mut direction): (XY, Direction)) -> Option<(XY, Direction)> {
+        for _ in 0..4 {
+            let heading = position + direction;
+            match self.grid.get(heading) {
+                Some('#') => direction = direction.turn_right(),
+                Some(_) => return Some((heading, direction)),
+                None => return None,
+            }
+        }
+        Some((position, direction))
+    }
+
+    /// Whether the guard goes round in circles, rather than settling on having left the lab.
+    fn loops(&self) -> bool {
+        let start = Some((self.start, Direction::Up));
+        cycle::brent(start, |guard| guard.and_then(|guard| self.step(guard)))
+            .state
+            .is_some()
+    }
+
+    /// Every place the guard stands, in order, until they leave the lab or are back where they
+    /// have already been.
+    fn route(&self) -> Vec<(XY, Direction)> {
+        let start = (self.start, Direction::Up);
+        let found = cycle::brent(Some(start), |guard| {
+            guard.and_then(|guard| self.step(guard))
+        });
+        let length = found.tail + found.state.map_or(0, |_| found.period);
+        std::iter::successors(Some(start), |&guard| self.step(guard))
+            .take(length)
+            .collect()
+    }
+
+    fn patrol(&self) -> Option<HashSet<XY>> {
+        if self.loops() {
+            return None;
+        }
+        let path =
+            std::iter::successors(Some((self.start, Direction::Up)), |&guard| self.step(guard));
+        Some(path.map(|(position, _)| position).collect())
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Lab;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Lab::new(input)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Self::Answer1> {
+        let path = map.patrol().ok_or(Error::NoSolution)?;
+        Ok(path.len())
+    }
+
+    fn part2(map: &Self::Model) -> Result<Self::Answer2> {
+        let mut map = map.clone();
+        let mut path = map.patrol().ok_or(Error::NoSolution)?;
+        path.remove(&map.start);
+        let obstacles: HashSet<XY> = path
+            .into_iter()
+            .filter(|&pos| {
+                map.grid.set(pos, '#');
+                let loops = map.loops();
+                map.grid.set(pos, '.');
+                loops
+            })
+            .collect();
+        Ok(obstacles.len())
+    }
+
+    /// The guard walking until it leaves the lab or starts going round in circles.
+    fn frames(map: &Self::Model, _params: &Params) -> Result<Frames> {
+        let mut trail = map.grid.clone();
+        let frames = map.route().into_iter().map(move |(position, direction)| {
+            trail[position] = 'X';
+            let mut frame = trail.clone();
+            frame[position] = char::from(direction);
+            frame
+        });
+        Ok(Box::new(frames))
+    }
+}
+
+#[aocd(2024, 6)]
+pub fn solution1() {
+    submit!(1, Day06::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 6)]
+pub fn solution2() {
+    submit!(2, Day06::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,88 @@
+use aocd::*;
+use itertools::{repeat_n, Itertools};
+use rayon::prelude::*;
+
+use crate::{
+    error::{self, Error, Result},
+    solution::Solution,
+    utils::parse,
+};
+
+#[derive(Debug)]
+pub struct Equation {
+    values: Vec<i64>,
+    total: i64,
+}
+
+impl TryFrom<&str> for Equation {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let (total, values) = parse::key_value(value)?;
+        let total: i64 = error::number(value, total)?;
+        let values: Vec<i64> = values
+            .split(' ')
+            .map(|n| error::number(value, n))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Equation { values, total })
+    }
+}
+
+impl Equation {
+    fn solve(&self, operators: Vec<u8>) -> i64 {
+        self.values
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(self.values[0], |acc, (idx, &num)| {
+                match operators[idx - 1] {
+                    0 => acc * num,
+                    1 => acc + num,
+                    _ => format!("{}{}", acc, num).parse().unwrap(),
+                }
+            })
+    }
+
+    fn solvable(&self, n_operators: u8) -> bool {
+        repeat_n(0..n_operators, self.values.len() - 1)
+            .multi_cartesian_product()
+            .any(|combo| self.solve(combo) == self.total)
+    }
+
+    fn score(&self, n_operators: u8) -> Option<i64> {
+        self.solvable(n_operators).then_some(self.total)
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = Vec<Equation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        input
+            .lines()
+            .map(|line| Equation::try_from(line).map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part1(data: &Self::Model) -> Result<Self::Answer1> {
+        Ok(data.par_iter().filter_map(|eq| eq.score(2)).sum())
+    }
+
+    fn part2(data: &Self::Model) -> Result<Self::Answer2> {
+        Ok(data.par_iter().filter_map(|eq| eq.score(3)).sum())
+    }
+}
+
+#[aocd(2024, 7)]
+pub fn solution1() {
+    submit!(1, Day07::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 7)]
+pub fn solution2() {
+    submit!(2, Day07::solve2(&input!()).unwrap());
+}
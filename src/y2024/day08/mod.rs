@@ -0,0 +1,101 @@
+use aocd::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::Result,
+    solution::Solution,
+    utils::{math, Grid, XY},
+};
+
+#[derive(Clone)]
+pub struct Antennas {
+    grid: Grid<char>,
+    nodes: HashMap<char, HashSet<XY>>,
+}
+
+impl Antennas {
+    fn new(input: &str) -> Result<Antennas> {
+        let grid = Grid::try_from(input)?;
+        let mut nodes = HashMap::<char, HashSet<XY>>::new();
+        for (position, &c) in grid.iter() {
+            if c != '.' {
+                nodes.entry(c).or_default().insert(position);
+            }
+        }
+        Ok(Antennas { grid, nodes })
+    }
+
+    fn find_anti_nodes(&self) -> HashSet<XY> {
+        let mut antinodes = HashSet::<XY>::new();
+        for (_, nodes) in self.nodes.iter() {
+            for &a in nodes.iter() {
+                for &b in nodes.iter() {
+                    if a != b {
+                        let antinode = a - (b - a);
+                        if self.grid.contains(antinode) {
+                            antinodes.insert(antinode);
+                        }
+                    }
+                }
+            }
+        }
+        antinodes
+    }
+
+    fn find_harmonic_anti_nodes(&self) -> HashSet<XY> {
+        let mut antinodes = HashSet::<XY>::new();
+        for (_, nodes) in self.nodes.iter() {
+            for &a in nodes.iter() {
+                for &b in nodes.iter() {
+                    if a != b {
+                        // Every grid point on the line, not just those a whole gap apart
+                        let diff = b - a;
+                        let g = math::gcd(diff.x, diff.y);
+                        let step = XY::new(diff.x / g, diff.y / g);
+                        let mut antinode_1 = a;
+                        let mut antinode_2 = a + step;
+                        while self.grid.contains(antinode_1) {
+                            antinodes.insert(antinode_1);
+                            antinode_1 = antinode_1 - step;
+                        }
+                        while self.grid.contains(antinode_2) {
+                            antinodes.insert(antinode_2);
+                            antinode_2 = antinode_2 + step;
+                        }
+                    }
+                }
+            }
+        }
+        antinodes
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Model = Antennas;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Antennas::new(input)
+    }
+
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1> {
+        Ok(grid.find_anti_nodes().len())
+    }
+
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2> {
+        Ok(grid.find_harmonic_anti_nodes().len())
+    }
+}
+
+#[aocd(2024, 8)]
+pub fn solution1() {
+    submit!(1, Day08::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 8)]
+pub fn solution2() {
+    submit!(2, Day08::solve2(&input!()).unwrap());
+}
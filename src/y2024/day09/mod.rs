@@ -0,0 +1,195 @@
+use aocd::*;
+use itertools::Itertools;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    render::Frames,
+    solution::{Params, Solution},
+    utils::{xy, Grid},
+};
+
+#[derive(Debug, Clone)]
+struct Block {
+    addr: usize,
+    length: usize,
+    value: usize,
+}
+
+impl Block {
+    fn fragment(&self) -> Vec<Block> {
+        (0..self.length)
+            .map(|i| Block {
+                addr: self.addr + i,
+                length: 1,
+                value: self.value,
+            })
+            .collect()
+    }
+
+    fn score(&self) -> usize {
+        (0..self.length).map(|i| (self.addr + i) * self.value).sum()
+    }
+
+    fn end(&self) -> usize {
+        self.addr + self.length
+    }
+
+    /// Moves the block into the leftmost gap before it that fits, if any.
+    fn relocate(&mut self, free_space: &mut [(usize, usize)]) -> bool {
+        for (addr, space) in free_space.iter_mut() {
+            if *addr < self.addr && self.length <= *space {
+                self.addr = *addr;
+                (*addr, *space) = (self.end(), *space - self.length);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diskmap {
+    blocks: Vec<Block>,
+}
+
+impl TryFrom<&str> for Diskmap {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let digit = |(i, c): (usize, char)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::new(value, &value[i..i + c.len_utf8()], "a digit"))
+        };
+        let mut start = 0;
+        let blocks: Vec<Block> = value
+            .trim_end()
+            .char_indices()
+            .chunks(2)
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut chunk)| {
+                let used = digit(chunk.next().unwrap())?;
+                let free = chunk.next().map_or(Ok(0), digit)?;
+                start += used + free;
+                Ok(Block {
+                    addr: start - used - free,
+                    length: used,
+                    value: i,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Diskmap { blocks })
+    }
+}
+
+impl Diskmap {
+    fn fragment(&mut self) {
+        self.blocks = self
+            .blocks
+            .iter()
+            .flat_map(|block| block.fragment())
+            .collect();
+    }
+
+    fn get_free_space(&mut self) -> Vec<(usize, usize)> {
+        self.blocks
+            .windows(2)
+            .filter_map(|pair| {
+                let space = pair[1].addr - pair[0].end();
+                (space > 0).then_some((pair[0].end(), space))
+            })
+            .collect()
+    }
+
+    fn compress(&mut self) {
+        let mut free_space = self.get_free_space();
+        for block in self.blocks.iter_mut().rev() {
+            block.relocate(&mut free_space);
+        }
+    }
+
+    /// The disk wrapped into rows of `width` blocks, each file drawn as the last digit of its id.
+    fn layout(&self, width: usize) -> Grid<char> {
+        let size = self.blocks.iter().map(Block::end).max().unwrap_or(0);
+        let mut grid = Grid::new(width, size.div_ceil(width), '.');
+        for block in self.blocks.iter() {
+            draw(&mut grid, block, Some(block.value));
+        }
+        grid
+    }
+
+    fn checksum(&self) -> usize {
+        self.blocks.iter().map(|block| block.score()).sum()
+    }
+}
+
+fn draw(grid: &mut Grid<char>, block: &Block, value: Option<usize>) {
+    let c = value.map_or('.', |value| {
+        char::from_digit((value % 10) as u32, 10).unwrap()
+    });
+    for addr in block.addr..block.end() {
+        let (x, y) = (addr % grid.width(), addr / grid.width());
+        grid.set(xy(x as i32, y as i32), c);
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Model = Diskmap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Diskmap::try_from(input)
+    }
+
+    fn part1(diskmap: &Self::Model) -> Result<Self::Answer1> {
+        let mut diskmap = diskmap.clone();
+        diskmap.fragment();
+        diskmap.compress();
+        Ok(diskmap.checksum())
+    }
+
+    fn part2(diskmap: &Self::Model) -> Result<Self::Answer2> {
+        let mut diskmap = diskmap.clone();
+        diskmap.compress();
+        Ok(diskmap.checksum())
+    }
+
+    /// Every block moved by the compaction of the given part.
+    fn frames(diskmap: &Self::Model, params: &Params) -> Result<Frames> {
+        let mut diskmap = diskmap.clone();
+        if params.get("part", 2)? == 1 {
+            diskmap.fragment();
+        }
+        let width: usize = params.get("width", 100)?;
+        let mut grid = diskmap.layout(width.max(1));
+        let mut free_space = diskmap.get_free_space();
+        let mut blocks = diskmap.blocks.into_iter().rev();
+        let first = grid.clone();
+        let moves = std::iter::from_fn(move || {
+            for mut block in blocks.by_ref() {
+                let before = block.clone();
+                if block.relocate(&mut free_space) {
+                    draw(&mut grid, &before, None);
+                    draw(&mut grid, &block, Some(block.value));
+                    return Some(grid.clone());
+                }
+            }
+            None
+        });
+        Ok(Box::new(std::iter::once(first).chain(moves)))
+    }
+}
+
+#[aocd(2024, 9)]
+pub fn solution1() {
+    submit!(1, Day09::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 9)]
+pub fn solution2() {
+    submit!(2, Day09::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use aocd::*;
+
+use crate::{
+    error::Result,
+    solution::Solution,
+    utils::{Grid, XY},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Height(u32);
+
+/// Fails on anything but a digit; [`Grid::parse`] reports where.
+impl TryFrom<char> for Height {
+    type Error = ();
+
+    fn try_from(c: char) -> std::result::Result<Self, ()> {
+        c.to_digit(10).map(Height).ok_or(())
+    }
+}
+
+fn trails(grid: &Grid<Height>, mut nines: HashMap<XY, i32>, start: XY) -> HashMap<XY, i32> {
+    let Height(current) = grid[start];
+    for neighbor in grid.neighbors(start) {
+        let Height(num) = grid[neighbor];
+        if num == current + 1 {
+            if num == 9 {
+                *nines.entry(neighbor).or_default() += 1;
+            } else {
+                nines = trails(grid, nines, neighbor);
+            }
+        }
+    }
+    nines
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Model = Grid<Height>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Grid::parse(input, "a height from 0 to 9")
+    }
+
+    fn part1(grid: &Self::Model) -> Result<Self::Answer1> {
+        let mut score = 0;
+        for position in grid.find_all(&Height(0)) {
+            let nines = trails(grid, HashMap::new(), position);
+            score += nines.len();
+        }
+        Ok(score)
+    }
+
+    fn part2(grid: &Self::Model) -> Result<Self::Answer2> {
+        let mut rating = 0;
+        for position in grid.find_all(&Height(0)) {
+            let nines = trails(grid, HashMap::new(), position);
+            rating += nines.values().copied().sum::<i32>();
+        }
+        Ok(rating)
+    }
+}
+
+#[aocd(2024, 10)]
+pub fn solution1() {
+    submit!(1, Day10::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 10)]
+pub fn solution2() {
+    submit!(2, Day10::solve2(&input!()).unwrap());
+}
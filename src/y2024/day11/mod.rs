@@ -0,0 +1,65 @@
+pub mod without_cached;
+
+use aocd::*;
+
+use crate::{error::Result, solution::Solution, utils::Memo};
+
+fn count_digits(num: u64) -> u32 {
+    num.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn blink(memo: &mut Memo<(u64, u64), u64>, stone: u64, blinks: u64) -> u64 {
+    memo.get((stone, blinks), |memo| {
+        let mut count = 0;
+        if blinks > 0 {
+            if stone == 0 {
+                count += blink(memo, 1, blinks - 1);
+            } else if count_digits(stone).is_multiple_of(2) {
+                let n = count_digits(stone);
+                let divisor = 10_u64.pow(n / 2);
+                count += blink(memo, stone / divisor, blinks - 1);
+                count += blink(memo, stone % divisor, blinks - 1);
+            } else {
+                count += blink(memo, stone * 2024, blinks - 1);
+            }
+        } else {
+            count += 1;
+        }
+        count
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Model = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(input
+            .split_whitespace()
+            .map(|n| n.parse())
+            .collect::<std::result::Result<_, _>>()?)
+    }
+
+    fn part1(stones: &Self::Model) -> Result<Self::Answer1> {
+        let mut memo = Memo::new();
+        Ok(stones.iter().map(|x| blink(&mut memo, *x, 25)).sum())
+    }
+
+    fn part2(stones: &Self::Model) -> Result<Self::Answer2> {
+        let mut memo = Memo::new();
+        Ok(stones.iter().map(|x| blink(&mut memo, *x, 75)).sum())
+    }
+}
+
+#[aocd(2024, 11)]
+pub fn solution1() {
+    submit!(1, Day11::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 11)]
+pub fn solution2() {
+    submit!(2, Day11::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::{error::Result, solution::Solution, y2024::day11::Day11};
+
+fn count_digits(num: u64) -> u32 {
+    num.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn blink(stone: u64, blinks: u64, cache: &mut HashMap<(u64, u64), u64>) -> u64 {
+    if let Some(cached) = cache.get(&(stone, blinks)).copied() {
+        cached
+    } else {
+        let mut count = 0;
+        if blinks > 0 {
+            if stone == 0 {
+                count += blink(1, blinks - 1, cache);
+            } else if count_digits(stone).is_multiple_of(2) {
+                let n = count_digits(stone);
+                let divisor = 10_u64.pow(n / 2);
+                count += blink(stone / divisor, blinks - 1, cache);
+                count += blink(stone % divisor, blinks - 1, cache);
+            } else {
+                count += blink(stone * 2024, blinks - 1, cache);
+            }
+        } else {
+            count += 1;
+        }
+        cache.insert((stone, blinks), count);
+        count
+    }
+}
+
+/// Day 11 with a plain `HashMap` threaded through `blink` instead of a `Memo`.
+pub struct WithoutCached;
+
+impl Solution for WithoutCached {
+    type Model = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Day11::parse(input)
+    }
+
+    fn part1(stones: &Self::Model) -> Result<Self::Answer1> {
+        let mut cache = HashMap::<(u64, u64), u64>::new();
+        Ok(stones.iter().map(|x| blink(*x, 25, &mut cache)).sum())
+    }
+
+    fn part2(stones: &Self::Model) -> Result<Self::Answer2> {
+        let mut cache = HashMap::<(u64, u64), u64>::new();
+        Ok(stones.iter().map(|x| blink(*x, 75, &mut cache)).sum())
+    }
+}
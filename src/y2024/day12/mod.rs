@@ -0,0 +1,105 @@
+use aocd::*;
+use std::collections::HashSet;
+
+use crate::{
+    error::Result,
+    solution::Solution,
+    utils::{cardinals, Grid, XY},
+};
+
+#[derive(Default, Debug)]
+pub struct Region {
+    gardens: HashSet<XY>,
+}
+
+impl Region {
+    fn has(&self, point: XY) -> bool {
+        self.gardens.contains(&point)
+    }
+
+    fn cost(&self) -> usize {
+        let perimeter: usize = self.gardens.iter().map(|garden| self.sides(garden)).sum();
+        perimeter * self.gardens.len()
+    }
+
+    fn cost2(&self) -> usize {
+        let perimeter: usize = self.gardens.iter().map(|garden| self.corners(garden)).sum();
+        perimeter * self.gardens.len()
+    }
+
+    fn sides(&self, point: &XY) -> usize {
+        point
+            .neighbors()
+            .iter()
+            .map(|&neighbor| !self.has(neighbor) as usize)
+            .sum()
+    }
+
+    fn corners(&self, point: &XY) -> usize {
+        cardinals()
+            .iter()
+            .map(|&step| {
+                let a = *point + step;
+                let b = *point + step.rotate_right();
+                let c = a + step.rotate_right();
+                let outer = !self.has(a) && !self.has(b);
+                let inner = self.has(a) && self.has(b) && !self.has(c);
+                (inner || outer) as usize
+            })
+            .sum()
+    }
+
+    fn build(&mut self, current: XY, grid: &Grid<char>, visited: &mut Grid<bool>) {
+        self.gardens.insert(current);
+        visited[current] = true;
+        for neighbor in grid.neighbors(current) {
+            if grid[neighbor] == grid[current] && !visited[neighbor] {
+                self.build(neighbor, grid, visited);
+            }
+        }
+    }
+}
+
+fn regions(input: &str) -> Result<Vec<Region>> {
+    let grid: Grid<char> = Grid::try_from(input)?;
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut regions = Vec::<Region>::new();
+    for garden in grid.positions() {
+        if !visited[garden] {
+            let mut region = Region::default();
+            region.build(garden, &grid, &mut visited);
+            regions.push(region);
+        }
+    }
+    Ok(regions)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Model = Vec<Region>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        regions(input)
+    }
+
+    fn part1(regions: &Self::Model) -> Result<Self::Answer1> {
+        Ok(regions.iter().map(|region| region.cost()).sum())
+    }
+
+    fn part2(regions: &Self::Model) -> Result<Self::Answer2> {
+        Ok(regions.iter().map(|region| region.cost2()).sum())
+    }
+}
+
+#[aocd(2024, 12)]
+pub fn solution1() {
+    submit!(1, Day12::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 12)]
+pub fn solution2() {
+    submit!(2, Day12::solve2(&input!()).unwrap());
+}
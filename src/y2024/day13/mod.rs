@@ -0,0 +1,92 @@
+use aocd::*;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    solution::Solution,
+    utils::{math, parse},
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+    upper: i64,
+    extra: i64,
+}
+
+impl TryFrom<&str> for Machine {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let mut caps = Vec::new();
+        for label in ["Button A", "Button B", "Prize"] {
+            let line = lines.next().unwrap_or(&s[s.len()..]);
+            let format = format!("`{label}: X.., Y..`");
+            let [x, y] = parse::key_value(line)
+                .ok()
+                .filter(|&(key, _)| key == label)
+                .and_then(|(_, coordinates)| parse::exactly(coordinates, &format).ok())
+                .ok_or_else(|| ParseError::new(s, line, format))?;
+            caps.push(x);
+            caps.push(y);
+        }
+        Ok(Machine {
+            a: (caps[0], caps[1]),
+            b: (caps[2], caps[3]),
+            prize: (caps[4], caps[5]),
+            upper: 100,
+            extra: 0,
+        })
+    }
+}
+
+impl Machine {
+    fn solve(&self) -> Option<i64> {
+        let (xa, ya) = self.a;
+        let (xb, yb) = self.b;
+        let (xp, yp) = (self.prize.0 + self.extra, self.prize.1 + self.extra);
+        let [a, b] = math::solve2([[xa, xb], [ya, yb]], [xp, yp])?;
+        let (a, b) = (a.integer()?, b.integer()?);
+        let in_bounds = (0..=self.upper).contains(&a) && (0..=self.upper).contains(&b);
+        in_bounds.then_some(a * 3 + b)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Model = Vec<Machine>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        parse::blocks(input)
+            .map(|block| Machine::try_from(block).map_err(|e| e.within(input, block)))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Model) -> Result<Self::Answer1> {
+        Ok(machines.iter().filter_map(|machine| machine.solve()).sum())
+    }
+
+    fn part2(machines: &Self::Model) -> Result<Self::Answer2> {
+        let mut machines = machines.clone();
+        machines.iter_mut().for_each(|machine| {
+            machine.upper = i64::MAX;
+            machine.extra = 10_000_000_000_000;
+        });
+        Ok(machines.iter().filter_map(|machine| machine.solve()).sum())
+    }
+}
+
+#[aocd(2024, 13)]
+pub fn solution1() {
+    submit!(1, Day13::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 13)]
+pub fn solution2() {
+    submit!(2, Day13::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,225 @@
+use core::f64;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use aocd::*;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    render::Frames,
+    solution::{Params, Solution},
+    utils::{cycle, math::Integer, parse, xy, Grid},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Robot {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl TryFrom<&str> for Robot {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        if !value.starts_with("p=") {
+            return Err(ParseError::new(value, value, "`p=X,Y v=DX,DY`").into());
+        }
+        let [x, y, dx, dy] = parse::exactly(value, "`p=X,Y v=DX,DY`")?;
+        Ok(Robot {
+            position: (x, y),
+            velocity: (dx, dy),
+        })
+    }
+}
+
+impl Robot {
+    fn go(&mut self, t: i32, x_limit: i32, y_limit: i32) {
+        let x = self.position.0 + self.velocity.0.mul_mod(t, x_limit);
+        let y = self.position.1 + self.velocity.1.mul_mod(t, y_limit);
+        self.position = (x.modulo(x_limit), y.modulo(y_limit));
+    }
+
+    fn quadrant(&self, x_limit: i32, y_limit: i32) -> Option<(usize, usize)> {
+        let x = match self.position.0.cmp(&(x_limit / 2)) {
+            Ordering::Greater => 1,
+            Ordering::Less => 0,
+            Ordering::Equal => return None,
+        };
+        let y = match self.position.1.cmp(&(y_limit / 2)) {
+            Ordering::Greater => 1,
+            Ordering::Less => 0,
+            Ordering::Equal => return None,
+        };
+        Some((x, y))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Robots {
+    robots: Vec<Robot>,
+    limits: (i32, i32),
+}
+
+impl TryFrom<&str> for Robots {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Ok(Robots {
+            robots: value
+                .lines()
+                .map(|line| Robot::try_from(line).map_err(|e| e.within(value, line)))
+                .collect::<Result<_>>()?,
+            limits: (0, 0),
+        })
+    }
+}
+
+impl Display for Robots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid())
+    }
+}
+
+impl Robots {
+    fn grid(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.limits.0 as usize, self.limits.1 as usize, ' ');
+        for robot in self.robots.iter() {
+            grid.set(xy(robot.position.0, robot.position.1), '#');
+        }
+        grid
+    }
+
+    fn set_limits(&mut self, x_limit: i32, y_limit: i32) {
+        self.limits = (x_limit, y_limit);
+    }
+
+    fn forward(&mut self, t: i32) {
+        self.robots
+            .iter_mut()
+            .for_each(|robot| robot.go(t, self.limits.0, self.limits.1));
+    }
+
+    fn quadrants(&self) -> HashMap<(usize, usize), usize> {
+        let mut quadrants = HashMap::<(usize, usize), usize>::new();
+        self.robots
+            .iter()
+            .filter_map(|robot: &Robot| robot.quadrant(self.limits.0, self.limits.1))
+            .for_each(|quadrant| {
+                *quadrants.entry(quadrant).or_default() += 1;
+            });
+        quadrants
+    }
+
+    fn regions(&self) -> Vec<Region> {
+        let mut points: HashSet<(i32, i32)> =
+            self.robots.iter().map(|robot| robot.position).collect();
+        let mut regions = Vec::<Region>::new();
+        while let Some(&garden) = points.iter().next() {
+            let mut region = Region::default();
+            region.build(garden, &mut points);
+            regions.push(region);
+        }
+        regions
+    }
+}
+
+#[derive(Default)]
+struct Region {
+    points: HashSet<(i32, i32)>,
+}
+
+impl Region {
+    fn build(&mut self, current: (i32, i32), points: &mut HashSet<(i32, i32)>) {
+        self.points.insert(current);
+        points.remove(&current);
+        for offset in [(-1, 0), (1, 0), (0, 1), (0, -1)] {
+            let neighbor = (current.0 + offset.0, current.1 + offset.1);
+            if points.contains(&neighbor) && !self.points.contains(&neighbor) {
+                self.build(neighbor, points);
+            }
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Model = Robots;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Model> {
+        let mut robots = Robots::try_from(input)?;
+        robots.set_limits(params.get("width", 101)?, params.get("height", 103)?);
+        Ok(robots)
+    }
+
+    fn part1(robots: &Self::Model) -> Result<Self::Answer1> {
+        let mut robots = robots.clone();
+        robots.forward(100);
+        Ok(robots.quadrants().values().copied().product())
+    }
+
+    fn part2(robots: &Self::Model) -> Result<Self::Answer2> {
+        let mut robots = robots.clone();
+
+        // Get periodicity
+        let period = cycle::brent(robots.clone(), |robots| {
+            let mut robots = robots.clone();
+            robots.forward(1);
+            robots
+        })
+        .period;
+
+        // Get number of regions within each step
+        let mut regions = Vec::new();
+        for _ in 0..period {
+            robots.forward(1);
+            let n: usize = robots.regions().len();
+            regions.push(n as f64);
+        }
+
+        // Look for the strongest outlier
+        let mean: f64 = regions.iter().sum::<f64>() / regions.len() as f64;
+        let sd: f64 =
+            (regions.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / regions.len() as f64).sqrt();
+        regions
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i + 1, (*x - mean).abs() / sd))
+            .filter(|&(_, z)| z > 10.)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+            .ok_or(Error::NoSolution)
+    }
+
+    fn frames(robots: &Self::Model, params: &Params) -> Result<Frames> {
+        let mut robots = robots.clone();
+        robots.forward(params.get("start", 0)?);
+        let steps: usize = params.get("steps", 100)?;
+        let frames = (0..=steps).map(move |step| {
+            if step > 0 {
+                robots.forward(1);
+            }
+            robots.grid()
+        });
+        Ok(Box::new(frames))
+    }
+}
+
+#[aocd(2024, 14)]
+pub fn solution1() {
+    submit!(1, Day14::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 14)]
+pub fn solution2() {
+    submit!(2, Day14::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,145 @@
+pub mod solution1;
+pub mod solution2;
+pub mod widened;
+
+use std::fmt::Display;
+
+use aocd::*;
+
+use crate::{
+    error::{ParseError, Result},
+    render::Frames,
+    solution::{Params, Solution},
+    utils::{parse, Direction, Grid, XY},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+/// Fails on anything but a tile; [`Grid::parse`] reports where.
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> std::result::Result<Self, ()> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Wall),
+            'O' => Ok(Tile::Box),
+            '[' => Ok(Tile::BoxLeft),
+            ']' => Ok(Tile::BoxRight),
+            '@' => Ok(Tile::Robot),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+            Tile::Robot => '@',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+pub struct Warehouse {
+    narrow: solution1::Map,
+    wide: solution2::Map,
+    moves: Vec<Direction>,
+}
+
+/// The warehouse tiles and where the robot starts.
+pub fn read_map(map: &str) -> Result<(Grid<Tile>, XY)> {
+    let grid = Grid::parse(map, "a warehouse tile")?;
+    let robot = grid
+        .find(&Tile::Robot)
+        .ok_or_else(|| parse::missing(map, "a robot `@`"))?;
+    Ok((grid, robot))
+}
+
+/// The robot's moves, which may be split across lines.
+pub fn read_moves(moves: &str) -> Result<Vec<Direction>> {
+    moves
+        .char_indices()
+        .filter(|&(_, c)| c != '\n' && c != '\r')
+        .map(|(i, c)| {
+            Direction::try_from(c).map_err(|_| {
+                ParseError::new(moves, &moves[i..i + c.len_utf8()], "one of ^v<>").into()
+            })
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Model = Warehouse;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let (map, moves) = parse::two_blocks(input)?;
+        Ok(Warehouse {
+            narrow: solution1::Map::new(map)?,
+            wide: solution2::Map::new(map)?,
+            moves: read_moves(moves).map_err(|e| e.within(input, moves))?,
+        })
+    }
+
+    fn part1(warehouse: &Self::Model) -> Result<Self::Answer1> {
+        let mut map = warehouse.narrow.clone();
+        map.process_moves(&warehouse.moves);
+        Ok(map.gps())
+    }
+
+    fn part2(warehouse: &Self::Model) -> Result<Self::Answer2> {
+        let mut map = warehouse.wide.clone();
+        map.process_moves(&warehouse.moves);
+        Ok(map.gps())
+    }
+
+    fn frames(warehouse: &Self::Model, params: &Params) -> Result<Frames> {
+        let moves = warehouse.moves.clone();
+        let steps = 0..=moves.len();
+        if params.get("wide", true)? {
+            let mut map = warehouse.wide.clone();
+            Ok(Box::new(steps.map(move |i| {
+                map.process_moves(&moves[i.saturating_sub(1)..i]);
+                map.frame()
+            })))
+        } else {
+            let mut map = warehouse.narrow.clone();
+            Ok(Box::new(steps.map(move |i| {
+                map.process_moves(&moves[i.saturating_sub(1)..i]);
+                map.frame()
+            })))
+        }
+    }
+}
+
+#[aocd(2024, 15)]
+pub fn solution1() {
+    submit!(1, Day15::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 15)]
+pub fn solution2() {
+    submit!(2, Day15::solve2(&input!()).unwrap());
+}
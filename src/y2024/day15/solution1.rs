@@ -0,0 +1,53 @@
+use crate::{
+    error::Result,
+    utils::{Direction, Grid, XY},
+    y2024::day15::{read_map, Tile},
+};
+
+#[derive(Clone)]
+pub struct Map {
+    robot: XY,
+    grid: Grid<Tile>,
+}
+
+impl Map {
+    pub fn new(map: &str) -> Result<Map> {
+        let (mut grid, robot) = read_map(map)?;
+        grid[robot] = Tile::Empty;
+        Ok(Map { robot, grid })
+    }
+
+    pub fn process_moves(&mut self, moves: &[Direction]) {
+        for &direction in moves {
+            let movement = XY::from(direction);
+            let mut next = self.robot + movement;
+            if self.grid.get(next) == Some(&Tile::Empty) {
+                self.robot = next;
+            } else {
+                let mut boxes = Vec::<XY>::new();
+                while self.grid.get(next) == Some(&Tile::Box) {
+                    boxes.push(next);
+                    next = next + movement;
+                }
+                if self.grid.get(next) == Some(&Tile::Empty) && !boxes.is_empty() {
+                    self.robot = self.robot + movement;
+                    self.grid[self.robot] = Tile::Empty;
+                    self.grid[next] = Tile::Box;
+                }
+            }
+        }
+    }
+
+    pub fn frame(&self) -> Grid<char> {
+        let mut frame = self.grid.map(|&tile| char::from(tile));
+        frame[self.robot] = char::from(Tile::Robot);
+        frame
+    }
+
+    pub fn gps(&self) -> i32 {
+        self.grid
+            .find_all(&Tile::Box)
+            .map(|pos| pos.y * 100 + pos.x)
+            .sum()
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::Result,
+    utils::{xy, Direction, Grid, XY},
+    y2024::day15::{read_map, Tile},
+};
+
+#[derive(Clone)]
+pub struct Map {
+    robot: XY,
+    grid: Grid<Tile>,
+}
+
+impl Map {
+    pub fn new(map: &str) -> Result<Map> {
+        let (grid, robot) = read_map(map)?;
+        let mut wide = Grid::new(grid.width() * 2, grid.height(), Tile::Empty);
+        for (pos, &tile) in grid.iter() {
+            let (left, right) = (xy(pos.x * 2, pos.y), xy(pos.x * 2 + 1, pos.y));
+            match tile {
+                Tile::Box => {
+                    wide[left] = Tile::BoxLeft;
+                    wide[right] = Tile::BoxRight;
+                }
+                Tile::Wall => {
+                    wide[left] = Tile::Wall;
+                    wide[right] = Tile::Wall;
+                }
+                _ => (),
+            }
+        }
+        Ok(Map {
+            robot: xy(robot.x * 2, robot.y),
+            grid: wide,
+        })
+    }
+
+    fn get(&self, position: XY) -> Tile {
+        *self.grid.get(position).unwrap_or(&Tile::Wall)
+    }
+
+    fn move_boxes(&mut self, box_group: HashSet<XY>, movement: XY) {
+        let inserts: Vec<(XY, Tile)> = box_group
+            .iter()
+            .map(|&pos| (pos + movement, self.get(pos)))
+            .collect();
+        for &pos in box_group.iter() {
+            self.grid[pos] = Tile::Empty;
+        }
+        for (pos, tile) in inserts {
+            self.grid[pos] = tile;
+        }
+    }
+
+    fn get_box_group(&self, pos: XY, movement: XY, mut current: HashSet<XY>) -> HashSet<XY> {
+        current.insert(pos);
+        let adjacent = match self.get(pos) {
+            Tile::BoxLeft => pos + xy(1, 0),
+            Tile::BoxRight => pos + xy(-1, 0),
+            _ => unreachable!(),
+        };
+        if !current.contains(&adjacent) {
+            current = self.get_box_group(adjacent, movement, current);
+        }
+        if matches!(self.get(pos + movement), Tile::BoxLeft | Tile::BoxRight) {
+            current = self.get_box_group(pos + movement, movement, current);
+        }
+        current
+    }
+
+    pub fn process_moves(&mut self, moves: &[Direction]) {
+        for &direction in moves {
+            let movement = XY::from(direction);
+            let next = self.robot + movement;
+            if self.get(next) == Tile::Empty {
+                self.robot = next;
+            } else if matches!(self.get(next), Tile::BoxLeft | Tile::BoxRight) {
+                let boxes = self.get_box_group(next, movement, HashSet::new());
+                if !boxes
+                    .iter()
+                    .any(|&pos| self.get(pos + movement) == Tile::Wall)
+                {
+                    self.robot = next;
+                    self.move_boxes(boxes, movement);
+                }
+            }
+        }
+    }
+
+    pub fn frame(&self) -> Grid<char> {
+        let mut frame = self.grid.map(|&tile| char::from(tile));
+        frame[self.robot] = char::from(Tile::Robot);
+        frame
+    }
+
+    pub fn gps(&self) -> i32 {
+        self.grid
+            .find_all(&Tile::BoxLeft)
+            .map(|pos| pos.y * 100 + pos.x)
+            .sum()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::Direction,
+    y2024::day15::{Day15, Warehouse},
+};
+
+/// Part one run on the part two engine. Horizontal moves are made at double resolution so that
+/// boxes and the robot end each move on an even column and act exactly like their narrow selves.
+pub struct Widened;
+
+impl Solution for Widened {
+    type Model = Warehouse;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Day15::parse(input)
+    }
+
+    fn part1(warehouse: &Self::Model) -> Result<Self::Answer1> {
+        let moves: Vec<Direction> = warehouse
+            .moves
+            .iter()
+            .flat_map(|&direction| match direction {
+                Direction::Left => vec![Direction::Left; 2],
+                // The robot stands on the left half of its cell, so it first steps onto the right
+                // half and steps back once it has pushed on into the next cell.
+                Direction::Right => vec![
+                    Direction::Right,
+                    Direction::Right,
+                    Direction::Right,
+                    Direction::Left,
+                ],
+                _ => vec![direction],
+            })
+            .collect();
+        let mut map = warehouse.wide.clone();
+        map.process_moves(&moves);
+        Ok(map
+            .frame()
+            .find_all(&'[')
+            .map(|pos| pos.y * 100 + pos.x / 2)
+            .sum())
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Answer2> {
+        Err(Error::Unimplemented)
+    }
+}
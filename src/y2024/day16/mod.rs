@@ -0,0 +1,80 @@
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        cardinals, parse,
+        search::{self, Paths},
+        xy, Grid, XY,
+    },
+};
+use aocd::*;
+use std::collections::HashSet;
+
+pub type Node = (XY, XY);
+
+fn dijkstra(start: Node, goal: XY, map: &Grid<char>) -> Paths<Node, i32> {
+    search::dijkstra(
+        start,
+        |&(position, direction)| {
+            cardinals().into_iter().filter_map(move |movement| {
+                let next = position + movement;
+                let cost = 1 + (direction != movement) as i32 * 1000;
+                map.get(next)
+                    .is_some_and(|&c| c != '#')
+                    .then_some(((next, movement), cost))
+            })
+        },
+        |node| node.0 == goal,
+    )
+}
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: XY,
+    goal: XY,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Model = Maze;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        let grid = Grid::try_from(input)?;
+        let start = grid
+            .find(&'S')
+            .ok_or_else(|| parse::missing(input, "a start tile `S`"))?;
+        let goal = grid
+            .find(&'E')
+            .ok_or_else(|| parse::missing(input, "an end tile `E`"))?;
+        Ok(Maze { grid, start, goal })
+    }
+
+    fn part1(maze: &Self::Model) -> Result<Self::Answer1> {
+        let paths = dijkstra((maze.start, xy(1, 0)), maze.goal, &maze.grid);
+        cardinals()
+            .iter()
+            .filter_map(|&dir| paths.cost(&(maze.goal, dir)))
+            .min()
+            .ok_or(Error::NoSolution)
+    }
+
+    fn part2(maze: &Self::Model) -> Result<Self::Answer2> {
+        let paths = dijkstra((maze.start, xy(1, 0)), maze.goal, &maze.grid);
+        let goals = cardinals().map(|dir| (maze.goal, dir));
+        let seats: HashSet<XY> = paths.nodes(goals).iter().map(|node| node.0).collect();
+        Ok(seats.len())
+    }
+}
+
+#[aocd(2024, 16)]
+pub fn solution1() {
+    submit!(1, Day16::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 16)]
+pub fn solution2() {
+    submit!(2, Day16::solve2(&input!()).unwrap());
+}
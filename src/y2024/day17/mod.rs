@@ -0,0 +1,159 @@
+use aocd::*;
+
+use crate::{
+    error::{self, Error, ParseError, Result},
+    solution::Solution,
+    utils::parse,
+};
+
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
+pub struct Machine {
+    ptr: usize,
+    a: isize,
+    b: isize,
+    c: isize,
+    program: Vec<u8>,
+    out: Vec<u8>,
+}
+
+impl TryFrom<&str> for Machine {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut lines = value.lines().filter(|line| !line.is_empty());
+        let mut next = |key: &str| -> Result<&str> {
+            let line = lines.next().unwrap_or(&value[value.len()..]);
+            match parse::key_value(line) {
+                Ok((found, text)) if found == key => Ok(text),
+                _ => Err(ParseError::new(value, line, format!("`{key}: ..`")).into()),
+            }
+        };
+        let a = error::number(value, next("Register A")?)?;
+        let b = error::number(value, next("Register B")?)?;
+        let c = error::number(value, next("Register C")?)?;
+        let text = next("Program")?;
+        let values: Vec<&str> = text.split(',').collect();
+        if !values.len().is_multiple_of(2) {
+            let expected = "pairs of an instruction and an operand";
+            return Err(ParseError::new(value, text, expected).into());
+        }
+        let mut program = Vec::with_capacity(values.len());
+        for (i, &text) in values.iter().enumerate() {
+            let n: u8 = error::number(value, text)?;
+            let combo = i % 2 == 1 && matches!(program.last(), Some(0 | 2 | 5 | 6 | 7));
+            if n > 7 {
+                return Err(ParseError::new(value, text, "a 3-bit number").into());
+            } else if combo && n == 7 {
+                return Err(ParseError::new(value, text, "a combo operand below 7").into());
+            }
+            program.push(n);
+        }
+        Ok(Machine {
+            ptr: 0,
+            a,
+            b,
+            c,
+            program,
+            out: Vec::new(),
+        })
+    }
+}
+
+impl Machine {
+    fn combo(&self, operand: u8) -> isize {
+        match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => unreachable!("combo operand {operand} is rejected when parsing"),
+        }
+    }
+
+    /// `a` divided by two to the power of a combo operand, which is zero once that exceeds `a`.
+    fn divide(&self, operand: u8) -> isize {
+        u32::try_from(self.combo(operand))
+            .ok()
+            .and_then(|power| 2_isize.checked_pow(power))
+            .map_or(0, |divisor| self.a / divisor)
+    }
+
+    /// Runs until the pointer leaves the program.
+    fn execute(&mut self) {
+        while let Some(&[opcode, operand]) = self.program.get(self.ptr..self.ptr + 2) {
+            let mut jump = 2;
+            match opcode {
+                0 => self.a = self.divide(operand),
+                1 => self.b ^= operand as isize,
+                2 => self.b = self.combo(operand) % 8,
+                3 => {
+                    if self.a != 0 {
+                        self.ptr = operand as usize;
+                        jump = 0;
+                    }
+                }
+                4 => self.b ^= self.c,
+                5 => self.out.push(self.combo(operand).rem_euclid(8) as u8),
+                6 => self.b = self.divide(operand),
+                7 => self.c = self.divide(operand),
+                _ => unreachable!("opcode {opcode} is rejected when parsing"),
+            }
+            self.ptr += jump;
+        }
+    }
+}
+
+/// Adapted from https://github.com/Praful/advent_of_code/blob/main/2024/src/day17.py
+fn solve(a: isize, idx: usize, possible: &mut Vec<isize>, default_machine: &Machine) {
+    for n in 0..8 {
+        let mut machine = default_machine.clone();
+        let candidate = (a << 3) | n;
+        machine.a = candidate;
+        machine.execute();
+        if machine.out == machine.program[(machine.program.len() - idx)..] {
+            if machine.out == machine.program {
+                possible.push(candidate);
+            } else {
+                solve(candidate, idx + 1, possible, default_machine);
+            }
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Model = Machine;
+    type Answer1 = String;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Machine::try_from(input)
+    }
+
+    fn part1(machine: &Self::Model) -> Result<Self::Answer1> {
+        let mut machine = machine.clone();
+        machine.execute();
+        let out: Vec<String> = machine.out.iter().map(|s| s.to_string()).collect();
+        Ok(out.join(","))
+    }
+
+    fn part2(machine: &Self::Model) -> Result<Self::Answer2> {
+        let mut possible = Vec::new();
+        solve(0, 1, &mut possible, machine);
+        possible.into_iter().min().ok_or(Error::NoSolution)
+    }
+}
+
+#[aocd(2024, 17)]
+pub fn solution1() {
+    submit!(1, Day17::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 17)]
+pub fn solution2() {
+    submit!(2, Day17::solve2(&input!()).unwrap());
+}
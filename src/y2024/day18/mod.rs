@@ -0,0 +1,106 @@
+use crate::{
+    error::{Error, Result},
+    solution::{Params, Solution},
+    utils::{parse, search, xy, Grid, XY},
+};
+use aocd::*;
+use std::collections::HashSet;
+
+fn read_data(input: &str) -> Result<Vec<XY>> {
+    input
+        .lines()
+        .map(|line| {
+            let (x, y) = parse::number_pair(line, ",").map_err(|e| e.within(input, line))?;
+            Ok(XY::new(x, y))
+        })
+        .collect()
+}
+
+fn a_star(start: XY, goal: XY, map: &Grid<char>) -> Vec<XY> {
+    let neighbors = |&current: &XY| {
+        map.neighbors(current)
+            .filter(|&neighbor| map[neighbor] == '.')
+            .map(|neighbor| (neighbor, 1))
+    };
+    search::astar(
+        start,
+        neighbors,
+        |node| node.manhattan(goal),
+        |&node| node == goal,
+    )
+    .map(|(path, _)| path)
+    .unwrap_or_default()
+}
+
+pub struct Memory {
+    bytes: Vec<XY>,
+    dim: i32,
+    fallen: usize,
+}
+
+impl Memory {
+    fn grid(&self) -> Grid<char> {
+        let size = self.dim as usize + 1;
+        Grid::new(size, size, '.')
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Model = Memory;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Model> {
+        Ok(Memory {
+            bytes: read_data(input)?,
+            dim: params.get("dim", 70)?,
+            fallen: params.get("bytes", 1024)?,
+        })
+    }
+
+    fn part1(memory: &Self::Model) -> Result<Self::Answer1> {
+        let mut grid = memory.grid();
+        for &obstacle in memory.bytes.iter().take(memory.fallen) {
+            grid.set(obstacle, '#');
+        }
+        let dim = memory.dim;
+        let path = a_star(xy(0, 0), xy(dim, dim), &grid);
+        if path.is_empty() {
+            return Err(Error::NoSolution);
+        }
+        Ok(path.len() - 1)
+    }
+
+    fn part2(memory: &Self::Model) -> Result<Self::Answer2> {
+        let mut grid = memory.grid();
+        let mut obstacles: Vec<XY> = memory.bytes.iter().rev().copied().collect();
+        let dim = memory.dim;
+        let mut path: HashSet<XY> = a_star(xy(0, 0), xy(dim, dim), &grid).into_iter().collect();
+        while let Some(obstacle) = obstacles.pop() {
+            grid.set(obstacle, '#');
+            if path.contains(&obstacle) {
+                path = a_star(xy(0, 0), xy(dim, dim), &grid).into_iter().collect();
+            }
+            if path.is_empty() {
+                return Ok(format!("{},{}", obstacle.x, obstacle.y));
+            }
+        }
+        Err(Error::NoSolution)
+    }
+}
+
+#[aocd(2024, 18)]
+pub fn solution1() {
+    submit!(1, Day18::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 18)]
+pub fn solution2() {
+    submit!(2, Day18::solve2(&input!()).unwrap());
+}
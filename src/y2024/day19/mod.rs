@@ -0,0 +1,81 @@
+use aocd::*;
+
+use crate::{
+    error::Result,
+    solution::Solution,
+    utils::{parse, Memo},
+};
+
+fn read_data(data: &str) -> Result<(Vec<String>, Vec<String>)> {
+    let (patterns, designs) = parse::two_blocks(data)?;
+    let patterns: Vec<String> = patterns.split(", ").map(String::from).collect();
+    let designs: Vec<String> = designs.lines().map(String::from).collect();
+    Ok((patterns, designs))
+}
+
+fn get_tokens(input: &str, dictionary: &[String]) -> Vec<String> {
+    dictionary
+        .iter()
+        .filter(|&token| input.contains(token.as_str()))
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Ways to make `input` from `tokens`. The count only depends on `input`, as long as `tokens`
+/// holds every pattern it contains, so one memo serves every design.
+fn combos<'a>(memo: &mut Memo<&'a str, usize>, input: &'a str, tokens: &[String]) -> usize {
+    memo.get(input, |memo| {
+        let mut sequence = 0;
+        for token in tokens.iter() {
+            if input.starts_with(token) {
+                let remainder = &input[token.len()..];
+                if remainder.is_empty() {
+                    sequence += 1;
+                } else {
+                    sequence += combos(memo, remainder, tokens);
+                }
+            }
+        }
+        sequence
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Model = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        read_data(input)
+    }
+
+    fn part1((dictionary, designs): &Self::Model) -> Result<Self::Answer1> {
+        let mut memo = Memo::new();
+        let mut total = 0;
+        for design in designs {
+            total += (combos(&mut memo, design, &get_tokens(design, dictionary)) > 0) as usize;
+        }
+        Ok(total)
+    }
+
+    fn part2((dictionary, designs): &Self::Model) -> Result<Self::Answer2> {
+        let mut memo = Memo::new();
+        let mut total = 0;
+        for design in designs {
+            total += combos(&mut memo, design, &get_tokens(design, dictionary));
+        }
+        Ok(total)
+    }
+}
+
+#[aocd(2024, 19)]
+pub fn solution1() {
+    submit!(1, Day19::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 19)]
+pub fn solution2() {
+    submit!(2, Day19::solve2(&input!()).unwrap());
+}
@@ -0,0 +1,83 @@
+use crate::{
+    error::{Error, Result},
+    solution::{Params, Solution},
+    utils::{parse, Grid, XY},
+};
+use aocd::*;
+
+/// The track from `start` to `goal`, which fails if it dead-ends first.
+fn path(start: XY, goal: XY, map: &Grid<char>) -> Result<Vec<XY>> {
+    let mut current = start;
+    let mut open = vec![start];
+    while current != goal {
+        current = map
+            .neighbors(current)
+            .find(|&neighbor| map[neighbor] != '#' && !open.contains(&neighbor))
+            .ok_or(Error::NoSolution)?;
+        open.push(current);
+    }
+    Ok(open)
+}
+
+fn solution(path: &[XY], cheat_time: usize, min_saving: usize) -> usize {
+    let mut cheats = 0;
+    for (i, &a) in path.iter().enumerate() {
+        for (j, &b) in path.iter().enumerate().skip(i + 1) {
+            let dist = a.manhattan(b) as usize;
+            if dist <= cheat_time && dist < (j - i) {
+                let shave = (j - i) - dist;
+                cheats += (shave >= min_saving) as usize;
+            }
+        }
+    }
+    cheats
+}
+
+pub struct Racetrack {
+    path: Vec<XY>,
+    min_saving: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Model = Racetrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Model> {
+        let grid = Grid::try_from(input)?;
+        let start = grid
+            .find(&'S')
+            .ok_or_else(|| parse::missing(input, "a start tile `S`"))?;
+        let end = grid
+            .find(&'E')
+            .ok_or_else(|| parse::missing(input, "an end tile `E`"))?;
+        Ok(Racetrack {
+            path: path(start, end, &grid)?,
+            min_saving: params.get("min_saving", 100)?,
+        })
+    }
+
+    fn part1(track: &Self::Model) -> Result<Self::Answer1> {
+        Ok(solution(&track.path, 2, track.min_saving))
+    }
+
+    fn part2(track: &Self::Model) -> Result<Self::Answer2> {
+        Ok(solution(&track.path, 20, track.min_saving))
+    }
+}
+
+#[aocd(2024, 20)]
+pub fn solution1() {
+    submit!(1, Day20::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 20)]
+pub fn solution2() {
+    submit!(2, Day20::solve2(&input!()).unwrap());
+}
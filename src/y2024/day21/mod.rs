@@ -0,0 +1,143 @@
+use crate::{
+    error::{Error, ParseError, Result},
+    solution::Solution,
+    utils::{search, xy, Direction, XY},
+};
+use aocd::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+struct Pad {
+    keys: HashMap<XY, char>,
+    ptr: XY,
+}
+
+impl Pad {
+    fn new(data: &[(XY, char)]) -> Pad {
+        let keys: HashMap<XY, char> = data.iter().cloned().collect();
+        let (&ptr, _) = keys.clone().iter().find(|&(_, &v)| v == 'A').unwrap();
+        Pad { keys, ptr }
+    }
+
+    fn paths(&self, c: char) -> Vec<Vec<XY>> {
+        let goal = self.pos(&c);
+        let neighbors = |current: &XY| {
+            current
+                .neighbors()
+                .into_iter()
+                .filter(|neighbor| self.keys.contains_key(neighbor))
+        };
+        search::bfs(self.ptr, neighbors, |&node| node == goal).all(&goal)
+    }
+
+    fn pos(&self, c: &char) -> XY {
+        let keys = self.keys.clone();
+        let (pos, _) = keys.iter().find(|&(_, v)| v == c).unwrap();
+        *pos
+    }
+
+    fn instruct(&mut self, desired: &str) -> Vec<String> {
+        let mut possible = Vec::new();
+        for c in desired.chars() {
+            let mut sub = Vec::new();
+            for path in self.paths(c) {
+                let mut s: String = path
+                    .windows(2)
+                    .map(|p| char::from(Direction::try_from(p[1] - p[0]).unwrap()))
+                    .collect();
+                s.push('A');
+                sub.push(s);
+            }
+            possible.push(sub);
+            self.ptr = self.pos(&c);
+        }
+        let total: Vec<String> = possible
+            .iter()
+            .cloned()
+            .multi_cartesian_product()
+            .map(|v| v.join(""))
+            .collect();
+        total
+    }
+}
+
+fn numeric(s: &str) -> usize {
+    s.chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |n, digit| n * 10 + digit as usize)
+}
+
+/// A code only has keys from the numeric keypad, so every key in it can be found.
+fn read_code<'a>(input: &str, line: &'a str) -> Result<&'a str> {
+    let expected = "a code of digits and `A`";
+    match line
+        .char_indices()
+        .find(|&(_, c)| !matches!(c, '0'..='9' | 'A'))
+    {
+        Some((i, _)) => Err(ParseError::new(input, &line[i..], expected).into()),
+        None if line.is_empty() => Err(ParseError::new(input, line, expected).into()),
+        None => Ok(line),
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Model = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        input
+            .lines()
+            .map(|line| read_code(input, line).map(String::from))
+            .collect()
+    }
+
+    fn part1(codes: &Self::Model) -> Result<Self::Answer1> {
+        let mut keypad = Pad::new(&[
+            (xy(0, 0), '7'),
+            (xy(1, 0), '8'),
+            (xy(2, 0), '9'),
+            (xy(0, 1), '4'),
+            (xy(1, 1), '5'),
+            (xy(2, 1), '6'),
+            (xy(0, 2), '1'),
+            (xy(1, 2), '2'),
+            (xy(2, 2), '3'),
+            (xy(1, 3), '0'),
+            (xy(2, 3), 'A'),
+        ]);
+        let mut dpad_1 = Pad::new(&[
+            (xy(1, 0), '^'),
+            (xy(2, 0), 'A'),
+            (xy(0, 1), '<'),
+            (xy(1, 1), 'v'),
+            (xy(2, 1), '>'),
+        ]);
+        let mut dpad_2 = dpad_1.clone();
+        let mut complexity = 0;
+        for code in codes {
+            let mut best = usize::MAX;
+            for i in keypad.instruct(code) {
+                for j in dpad_1.instruct(&i) {
+                    for k in dpad_2.instruct(&j) {
+                        best = best.min(k.len());
+                    }
+                }
+            }
+            complexity += best * numeric(code);
+        }
+        Ok(complexity)
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Answer2> {
+        Err(Error::Unimplemented)
+    }
+}
+
+#[aocd(2024, 21)]
+pub fn solution1() {
+    submit!(1, Day21::solve1(&input!()).unwrap());
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use aocd::*;
+
+use crate::{error::Result, solution::Solution};
+
+type Seq = (isize, isize, isize, isize);
+
+fn gen(mut num: usize) -> usize {
+    num ^= num * 64;
+    num %= 16777216;
+    num ^= num / 32;
+    num %= 16777216;
+    num ^= num * 2048;
+    num % 16777216
+}
+
+fn prices(seed: usize, n: usize) -> HashMap<Seq, usize> {
+    let mut prices = Vec::new();
+    (0..n).fold(seed, |num, _| {
+        prices.push(num as isize % 10);
+        gen(num)
+    });
+    let diff: Vec<isize> = prices.windows(2).map(|x| x[1] - x[0]).collect();
+    let mut changes = HashMap::<Seq, usize>::new();
+    diff.windows(4).enumerate().for_each(|(i, diffs)| {
+        let seq = (diffs[0], diffs[1], diffs[2], diffs[3]);
+        changes.entry(seq).or_insert(prices[i + 4] as usize);
+    });
+    changes
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Model = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        Ok(input
+            .lines()
+            .map(|x| x.parse())
+            .collect::<std::result::Result<_, _>>()?)
+    }
+
+    fn part1(seeds: &Self::Model) -> Result<Self::Answer1> {
+        let k = 2000;
+        Ok(seeds.iter().map(|&x| (0..k).fold(x, |n, _| gen(n))).sum())
+    }
+
+    fn part2(secrets: &Self::Model) -> Result<Self::Answer2> {
+        let mut totals = HashMap::<Seq, usize>::new();
+        for &secret in secrets {
+            for (seq, price) in prices(secret, 2000) {
+                *totals.entry(seq).or_insert(0) += price;
+            }
+        }
+        let (_, best) = totals.iter().max_by_key(|&(_, &v)| v).unwrap();
+        Ok(*best)
+    }
+}
+
+#[aocd(2024, 22)]
+pub fn solution1() {
+    submit!(1, Day22::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 22)]
+pub fn solution2() {
+    submit!(2, Day22::solve2(&input!()).unwrap());
+}
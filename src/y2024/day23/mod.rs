@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use aocd::*;
+
+use crate::{error::Result, solution::Solution, utils::parse};
+
+type Graph = HashMap<String, HashSet<String>>;
+
+fn read_graph(input: &str) -> Result<Graph> {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (a, b) = parse::pair(line, "-").map_err(|e| e.within(input, line))?;
+        graph
+            .entry(a.to_string())
+            .or_default()
+            .insert(b.to_string());
+        graph
+            .entry(b.to_string())
+            .or_default()
+            .insert(a.to_string());
+    }
+    Ok(graph)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Model = Graph;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        read_graph(input)
+    }
+
+    fn part1(graph: &Self::Model) -> Result<Self::Answer1> {
+        let mut clusters = HashSet::<(&String, &String, &String)>::new();
+        for (node, adjacent) in graph.iter() {
+            for a in adjacent.iter() {
+                for b in adjacent.iter() {
+                    if a != b && graph[a].contains(b) {
+                        let mut cluster = [node, a, b];
+                        cluster.sort();
+                        if node.starts_with('t') || a.starts_with('t') || b.starts_with('t') {
+                            clusters.insert((cluster[0], cluster[1], cluster[2]));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(clusters.len())
+    }
+
+    fn part2(graph: &Self::Model) -> Result<Self::Answer2> {
+        let mut largest = HashSet::new();
+        let mut best = 0;
+        for node in graph.keys() {
+            let mut cluster = HashSet::new();
+            cluster.insert(node);
+            for other in graph.keys().filter(|n| *n != node) {
+                if cluster.iter().all(|member| graph[other].contains(*member)) {
+                    cluster.insert(other);
+                }
+            }
+            if cluster.len() > best {
+                largest = cluster;
+                best = largest.len();
+            }
+        }
+        let mut largest: Vec<String> = largest.iter().map(|s| s.to_string()).collect();
+        largest.sort();
+        Ok(largest.join(","))
+    }
+}
+
+#[aocd(2024, 23)]
+pub fn solution1() {
+    submit!(1, Day23::solve1(&input!()).unwrap());
+}
+
+#[aocd(2024, 23)]
+pub fn solution2() {
+    submit!(2, Day23::solve2(&input!()).unwrap());
+}
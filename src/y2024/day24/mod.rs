@@ -0,0 +1,118 @@
+use std::collections::{HashMap, HashSet};
+
+use aocd::*;
+use itertools::Itertools;
+
+use crate::{
+    error::{self, Error, ParseError, Result},
+    solution::Solution,
+    utils::parse,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn apply(self, a: u8, b: u8) -> u8 {
+        match self {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Wire {
+    Gates(String, String, Op),
+    Bit(u8),
+}
+
+impl Wire {
+    fn value(&self, lookup: &HashMap<String, Wire>) -> u8 {
+        match self {
+            Wire::Gates(a, b, op) => op.apply(lookup[a].value(lookup), lookup[b].value(lookup)),
+            Wire::Bit(bit) => *bit,
+        }
+    }
+}
+
+fn read_bit(line: &str) -> Result<(String, Wire)> {
+    let (name, bit) = parse::key_value(line)?;
+    match error::number(line, bit)? {
+        bit @ (0 | 1) => Ok((name.to_string(), Wire::Bit(bit))),
+        _ => Err(ParseError::new(line, bit, "a bit").into()),
+    }
+}
+
+/// The inputs, operation and output wire of an `a OP b -> c` line.
+fn read_gate(line: &str) -> Result<([&str; 2], Op, &str)> {
+    let (inputs, name) = parse::pair(line, " -> ")?;
+    let (wires, op) = match inputs.split(' ').collect::<Vec<_>>()[..] {
+        [a, "AND", b] => ([a, b], Op::And),
+        [a, "OR", b] => ([a, b], Op::Or),
+        [a, "XOR", b] => ([a, b], Op::Xor),
+        _ => return Err(ParseError::new(line, inputs, "`a OP b`").into()),
+    };
+    Ok((wires, op, name))
+}
+
+fn read_wires(input: &str) -> Result<HashMap<String, Wire>> {
+    let (bits, gates) = parse::two_blocks(input)?;
+    let mut wires = bits
+        .lines()
+        .map(|line| read_bit(line).map_err(|e| e.within(input, line)))
+        .collect::<Result<HashMap<_, _>>>()?;
+    let gates = gates
+        .lines()
+        .map(|line| read_gate(line).map_err(|e| e.within(input, line)))
+        .collect::<Result<Vec<_>>>()?;
+    let outputs: HashSet<&str> = gates.iter().map(|&(_, _, name)| name).collect();
+    for ([a, b], op, name) in gates {
+        if let Some(unknown) = [a, b]
+            .into_iter()
+            .find(|wire| !wires.contains_key(*wire) && !outputs.contains(wire))
+        {
+            return Err(ParseError::new(input, unknown, "a defined wire").into());
+        }
+        wires.insert(
+            name.to_string(),
+            Wire::Gates(a.to_string(), b.to_string(), op),
+        );
+    }
+    Ok(wires)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Model = HashMap<String, Wire>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        read_wires(input)
+    }
+
+    fn part1(wires: &Self::Model) -> Result<Self::Answer1> {
+        let mut out = Vec::new();
+        for z_wire in wires.keys().filter(|k| k.starts_with('z')).sorted() {
+            let bit = wires.get(z_wire).unwrap().value(wires);
+            out.insert(0, bit);
+        }
+        Ok(out.iter().fold(0, |n, &bit| (n << 1) | (bit as u64)))
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Answer2> {
+        Err(Error::Unimplemented)
+    }
+}
+
+#[aocd(2024, 24)]
+pub fn solution1() {
+    submit!(1, Day24::solve1(&input!()).unwrap());
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use aocd::*;
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+    utils::{parse, xy, Grid, XY},
+};
+
+type Schematics = (Vec<HashSet<XY>>, Vec<HashSet<XY>>);
+
+fn read_data(input: &str) -> Result<Schematics> {
+    let mut keys = Vec::<HashSet<XY>>::new();
+    let mut locks = Vec::<HashSet<XY>>::new();
+    for block in parse::blocks(input) {
+        let grid: Grid<char> = Grid::try_from(block).map_err(|e| e.within(input, block))?;
+        let pins = grid.find_all(&'#').collect();
+        if grid.get(xy(0, 0)) == Some(&'#') {
+            locks.push(pins);
+        } else {
+            keys.push(pins);
+        }
+    }
+    Ok((keys, locks))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Model = Schematics;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Model> {
+        read_data(input)
+    }
+
+    fn part1((keys, locks): &Self::Model) -> Result<Self::Answer1> {
+        let mut count = 0;
+        for key in keys.iter() {
+            for lock in locks.iter() {
+                if key.intersection(lock).count() == 0 {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Answer2> {
+        Err(Error::Unimplemented)
+    }
+}
+
+#[aocd(2024, 25)]
+pub fn solution1() {
+    submit!(1, Day25::solve1(&input!()).unwrap());
+}
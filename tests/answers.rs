@@ -0,0 +1,114 @@
+use std::{env, fs};
+
+use adventofcode::{
+    answer::Answer,
+    answers::{hash, Answers, Status},
+    error::Error,
+    toml::{self, Value},
+};
+
+#[test]
+fn answers_survive_a_save_and_load() {
+    let dir = env::temp_dir().join(format!("adventofcode-answers-{}", std::process::id()));
+    let path = Answers::path(&dir, 2024);
+    let mut answers = Answers::default();
+    let recorded = [
+        (1, Answer::Signed(-7)),
+        (2, Answer::Unsigned(u128::MAX)),
+        (3, Answer::Text("5".to_string())),
+        (4, Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string())),
+    ];
+    for (day, answer) in recorded.iter() {
+        answers.record(*day, 1, "input", answer);
+    }
+    answers.save(&path).unwrap();
+    let loaded = Answers::load(&path).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+
+    assert_eq!(loaded, answers);
+    for (day, answer) in recorded.iter() {
+        assert_eq!(loaded.check(*day, 1, "input", answer), Status::Pass);
+    }
+    let text = loaded.days[&3].part1.as_ref().unwrap();
+    assert!(matches!(text, Answer::Text(s) if s == "5"), "{text:?}");
+    assert!(matches!(loaded.days[&1].part1, Some(Answer::Signed(-7))));
+}
+
+#[test]
+fn text_and_integers_compare_by_written_form() {
+    assert_eq!(Answer::Text("5".to_string()), Answer::from(5_u8));
+    assert_eq!(Answer::from(5_i64), Answer::Text("5".to_string()));
+    assert_ne!(Answer::Text("05".to_string()), Answer::from(5_u8));
+    assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+}
+
+#[test]
+fn toml_escapes_and_comments() {
+    let input = "# answers\n\
+                 name = \"say \\\"hi\\\" # not a comment\\n\\ttab \\\\\" # a comment\n\
+                 \n\
+                 [day01]   # the first day\n\
+                 part1 = 1_000\n\
+                 \"part2\" = -5\n\
+                 done = true\n";
+    let root = toml::parse(input).unwrap();
+    assert_eq!(
+        root["name"],
+        Value::String("say \"hi\" # not a comment\n\ttab \\".to_string())
+    );
+    let day = root["day01"].as_table().unwrap();
+    assert_eq!(day["part1"], Value::Integer(1000));
+    assert_eq!(day["part2"], Value::Integer(-5));
+    assert_eq!(day["done"], Value::Boolean(true));
+    assert_eq!(toml::parse(&toml::to_string(&root)), Ok(root));
+
+    for malformed in [
+        "a = \"open",
+        "a = \"\\q\"",
+        "a = 1.5",
+        "just a key",
+        "[day01",
+        "[day01]\npart1 = 1\n[day02]\n[day01]\npart2 = 2",
+        "day01 = 1\n[day01]",
+    ] {
+        assert!(toml::parse(malformed).is_err(), "{malformed:?} parsed");
+    }
+    let duplicate = toml::parse("[day01]\npart1 = 1\n\n[day01]\npart2 = 2");
+    assert_eq!(
+        duplicate,
+        Err(Error::Parse("toml line 4: duplicate table".to_string()))
+    );
+}
+
+#[test]
+fn checks_classify_answers() {
+    let input = "3   4\n4   3";
+    let mut answers = Answers::default();
+    answers.record(1, 1, &hash(input), &Answer::from(11_u64));
+    let check = |day, part, input: &str, answer: u64| {
+        answers.check(day, part, &hash(input), &Answer::from(answer))
+    };
+    assert_eq!(check(1, 1, input, 11), Status::Pass);
+    assert_eq!(check(1, 1, input, 12), Status::Fail(Answer::from(11_u64)));
+    assert_eq!(check(1, 2, input, 31), Status::Missing);
+    assert_eq!(check(2, 1, input, 11), Status::Missing);
+    assert_eq!(check(1, 1, "3   4", 11), Status::Changed);
+}
+
+#[test]
+fn recording_a_new_input_replaces_the_entry() {
+    let (old, new) = (hash("1\n2"), hash("1\n3"));
+    assert_ne!(old, new);
+    let mut answers = Answers::default();
+    answers.record(5, 1, &old, &Answer::from(1_u8));
+    answers.record(5, 2, &old, &Answer::from(2_u8));
+    answers.record(5, 1, &new, &Answer::from(3_u8));
+    let recorded = &answers.days[&5];
+    assert_eq!(recorded.input, new);
+    assert_eq!(recorded.part1, Some(Answer::from(3_u8)));
+    assert_eq!(recorded.part2, None);
+
+    answers.record(5, 2, &new, &Answer::from(4_u8));
+    assert_eq!(answers.days[&5].part1, Some(Answer::from(3_u8)));
+    assert_eq!(answers.days[&5].part2, Some(Answer::from(4_u8)));
+}
@@ -0,0 +1,96 @@
+use adventofcode::utils::cycle::{self, Cycle};
+
+/// 0, 1, 2 lead into 3..=9, which then repeats.
+fn rho(x: &u32) -> u32 {
+    if *x < 9 {
+        x + 1
+    } else {
+        3
+    }
+}
+
+/// A quadratic map over a small modulus, whose tail and period are not obvious.
+fn lcg(x: &u64) -> u64 {
+    (x * x + 7) % 1009
+}
+
+fn brute_force(start: u64, step: fn(&u64) -> u64, n: usize) -> u64 {
+    (0..n).fold(start, |state, _| step(&state))
+}
+
+#[test]
+fn detectors_agree_on_tail_and_period() {
+    let expected = Cycle {
+        tail: 3,
+        period: 7,
+        state: 3,
+    };
+    assert_eq!(cycle::brent(0, rho), expected);
+    assert_eq!(cycle::floyd(0, rho), expected);
+    assert_eq!(cycle::hashed(0, rho), expected);
+
+    // Starting inside the cycle leaves no tail
+    assert_eq!(
+        cycle::brent(5, rho),
+        Cycle {
+            tail: 0,
+            period: 7,
+            state: 5
+        }
+    );
+    assert_eq!(cycle::floyd(9, rho).tail, 0);
+
+    // A fixed point is a cycle of one
+    assert_eq!(
+        cycle::brent(4, |_| 4),
+        Cycle {
+            tail: 0,
+            period: 1,
+            state: 4
+        }
+    );
+    assert_eq!(
+        cycle::floyd(1, |_| 4),
+        Cycle {
+            tail: 1,
+            period: 1,
+            state: 4
+        }
+    );
+
+    let found = cycle::hashed(2, lcg);
+    assert!(found.tail > 0);
+    assert_eq!(cycle::brent(2, lcg), found);
+    assert_eq!(cycle::floyd(2, lcg), found);
+    assert_eq!(brute_force(2, lcg, found.tail), found.state);
+    assert_eq!(brute_force(2, lcg, found.tail + found.period), found.state);
+}
+
+#[test]
+fn indices_fold_into_the_cycle() {
+    let found = cycle::brent(0, rho);
+    assert_eq!(found.index(2), 2);
+    assert_eq!(found.index(3), 3);
+    assert_eq!(found.index(10), 3);
+    assert_eq!(found.index(16), 9);
+}
+
+#[test]
+fn nth_jumps_far_ahead() {
+    for n in [0, 1, 2, 3, 9, 10, 100, 12345] {
+        assert_eq!(cycle::nth(2, lcg, n), brute_force(2, lcg, n), "{n}");
+    }
+    let n = 1_000_000_000_000;
+    let found = cycle::hashed(2, lcg);
+    let expected = brute_force(2, lcg, found.index(n));
+    assert_eq!(cycle::nth(2, lcg, n), expected);
+    assert_eq!(
+        brute_force(
+            2,
+            lcg,
+            found.tail + (n - found.tail) % found.period + 2 * found.period
+        ),
+        expected
+    );
+    assert_eq!(cycle::nth(0, rho, n), 3 + ((n - 3) % 7) as u32);
+}
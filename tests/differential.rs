@@ -0,0 +1,46 @@
+use adventofcode::{
+    differential::{self, Variant},
+    fixtures,
+    generate::{self, Rng},
+    solution::Params,
+};
+
+fn generated(day: u8, rng: &mut Rng) -> String {
+    match day {
+        11 => generate::stones(rng, 8, 7),
+        15 => generate::warehouse(rng, 20, 12, 400),
+        _ => panic!("no generator for day {day}"),
+    }
+}
+
+fn check(variant: &Variant, input: &str, params: &Params) -> Vec<String> {
+    differential::compare(variant, input, params)
+        .iter()
+        .map(|divergence| format!("{divergence}\n{input}"))
+        .collect()
+}
+
+#[test]
+fn variants_agree_on_examples() {
+    for variant in differential::VARIANTS.iter() {
+        let fixtures = fixtures::load(fixtures::DIR, variant.year, variant.day).unwrap();
+        let failures: Vec<String> = fixtures
+            .iter()
+            .flat_map(|fixture| check(variant, &fixture.input, &fixture.params))
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
+
+#[test]
+fn variants_agree_on_generated_inputs() {
+    for variant in differential::VARIANTS.iter() {
+        let failures: Vec<String> = (0..20)
+            .flat_map(|seed| {
+                let input = generated(variant.day, &mut Rng::new(seed));
+                check(variant, &input, &Params::default())
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
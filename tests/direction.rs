@@ -0,0 +1,78 @@
+use adventofcode::{
+    error::Error,
+    utils::{cardinals, xy, Direction, XY},
+};
+
+#[test]
+fn turns_compose() {
+    use Direction::*;
+    assert_eq!(Up.turn_right(), Right);
+    assert_eq!(Right.turn_right(), Down);
+    assert_eq!(Up.turn_left(), Left);
+    assert_eq!(Left.turn_left(), Down);
+    assert_eq!(Up.reverse(), Down);
+    assert_eq!(Right.reverse(), Left);
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_eq!(XY::from(direction.reverse()), -XY::from(direction));
+    }
+}
+
+#[test]
+fn chars_and_steps_round_trip() {
+    let written: String = Direction::ALL.iter().map(|&d| char::from(d)).collect();
+    assert_eq!(written, "^>v<");
+    for c in "^>v<".chars() {
+        let direction = Direction::try_from(c).unwrap();
+        assert_eq!(char::from(direction), c);
+        assert_eq!(direction.to_string(), c.to_string());
+    }
+    assert!(matches!(Direction::try_from('x'), Err(Error::Parse(_))));
+
+    for direction in Direction::ALL {
+        assert_eq!(Direction::try_from(XY::from(direction)), Ok(direction));
+    }
+    assert_eq!(XY::from(Direction::Up), xy(0, -1));
+    assert_eq!(xy(3, 3) + Direction::Left, xy(2, 3));
+    assert!(Direction::try_from(xy(1, 1)).is_err());
+    assert!(Direction::try_from(xy(0, 0)).is_err());
+}
+
+#[test]
+fn rotations_follow_turns() {
+    for direction in Direction::ALL {
+        let step = XY::from(direction);
+        assert_eq!(step.rotate_right(), XY::from(direction.turn_right()));
+        assert_eq!(step.rotate_left(), XY::from(direction.turn_left()));
+    }
+    let offset = xy(3, -2);
+    assert_eq!(offset.rotate_right(), xy(2, 3));
+    assert_eq!(offset.rotate_left(), xy(-2, -3));
+    assert_eq!(offset.rotate_right().rotate_left(), offset);
+    assert_eq!(offset.rotate_right().rotate_right(), -offset);
+    let wide = XY::<i64>::from(offset);
+    assert_eq!(wide.rotate_right(), XY::new(2_i64, 3));
+}
+
+#[test]
+fn distances() {
+    let (a, b) = (xy(1, -2), xy(-3, 5));
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(b.manhattan(a), 11);
+    assert_eq!(a.manhattan(a), 0);
+    assert_eq!(a.chebyshev(a), 0);
+    let far = XY::new(-3_000_000_000_i64, 0);
+    assert_eq!(far.manhattan(XY::new(3_000_000_000, 1)), 6_000_000_001);
+    assert_eq!(far.chebyshev(XY::new(3_000_000_000, 1)), 6_000_000_000);
+
+    let origin = xy(0, 0);
+    assert!(cardinals().iter().all(|&step| origin.manhattan(step) == 1));
+    assert!(origin
+        .neighbors8()
+        .iter()
+        .all(|&n| origin.chebyshev(n) == 1));
+    assert_eq!(origin.neighbors8()[0], xy(-1, -1));
+}
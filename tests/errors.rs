@@ -0,0 +1,121 @@
+use adventofcode::{
+    error::{Error, ParseError},
+    registry,
+    solution::{Params, Solution},
+    y2024::{day06::Day06, day20::Day20},
+};
+
+fn position(error: &ParseError) -> (usize, usize, &str) {
+    (error.line, error.column, error.text.as_str())
+}
+
+/// The located error from parsing `input` for a 2024 day, which must not parse.
+fn syntax(day: u8, input: &str) -> ParseError {
+    let entry = registry::find(2024, day).unwrap();
+    match (entry.parse)(input, &Params::default()) {
+        Err(Error::Syntax(error)) => error,
+        Err(error) => panic!("day {day}: unlocated {error}"),
+        Ok(_) => panic!("day {day}: parsed {input:?}"),
+    }
+}
+
+#[test]
+fn errors_on_a_middle_line() {
+    let input = "1 2 3\n4 x 6\n7 8 9";
+    let line = input.lines().nth(1).unwrap();
+    let error = ParseError::new(input, &line[2..], "a number");
+    assert_eq!(position(&error), (2, 3, "x 6"));
+
+    // The same error found in the line alone, then placed in the input
+    let error = ParseError::new(line, &line[2..], "a number").within(input, line);
+    assert_eq!(position(&error), (2, 3, "x 6"));
+}
+
+#[test]
+fn errors_within_a_block() {
+    let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                 Button A: X+26, Y+66\nButton B: X+67, Y+21\nPrice: X=12748, Y=12176";
+    let entry = registry::find(2024, 13).unwrap();
+    match (entry.parse)(input, &Params::default()) {
+        Err(Error::Syntax(error)) => {
+            assert_eq!(error.day, Some(13));
+            assert_eq!(position(&error), (7, 1, "Price: X=12748, Y=12176"));
+        }
+        Err(error) => panic!("{error}"),
+        Ok(_) => panic!("parsed a machine without a prize"),
+    }
+}
+
+#[test]
+fn columns_count_characters_not_bytes() {
+    let input = "naïve café ☕ 12\nélan 34";
+    let first = input.lines().next().unwrap();
+    let cup = first.find('☕').unwrap();
+    let error = ParseError::new(input, &first[cup..], "a number");
+    assert_eq!(position(&error), (1, 12, "☕ 12"));
+
+    // Within a fragment that itself starts after multi-byte text on the first line
+    let fragment = &first[cup..];
+    let error =
+        ParseError::new(fragment, &fragment["☕ ".len()..], "a word").within(input, fragment);
+    assert_eq!(position(&error), (1, 14, "12"));
+
+    let second = input.lines().nth(1).unwrap();
+    let error = ParseError::new(input, &second["élan ".len()..], "a word");
+    assert_eq!(position(&error), (2, 6, "34"));
+}
+
+#[test]
+fn malformed_puzzles_point_at_the_problem() {
+    for (day, input, expected) in [
+        (1, "3   4\n4\n", (2, 1, "4")),
+        (1, "3   4\n4   x\n", (2, 5, "x")),
+        (6, "..#\n...", (2, 4, "")),
+        (9, "12x4", (1, 3, "x")),
+        (10, "012\n3x5", (2, 2, "x")),
+        (15, "#x@\n\n<", (1, 2, "x")),
+        (15, "#..\n#..\n\n<", (2, 4, "")),
+        (15, "#.@\n\n<<\n>x", (4, 2, "x")),
+        (15, "#.@\n", (2, 1, "")),
+        (16, "#S.\n...", (2, 4, "")),
+        (
+            17,
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5",
+            (5, 10, "0,1,5"),
+        ),
+        (
+            17,
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4",
+            (5, 14, "8"),
+        ),
+        (
+            17,
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7",
+            (5, 16, "7"),
+        ),
+        (18, "5,4\n3\n", (2, 1, "3")),
+        (18, "5,4\n3,y\n", (2, 3, "y")),
+        (20, "S.\n..", (2, 3, "")),
+        (24, "x00: 1\n\nx00 AND y00 -> z00", (3, 9, "y00")),
+        (24, "x00: 1\n\nx00 NAND x00 -> z00", (3, 1, "x00 NAND x00")),
+    ] {
+        let error = syntax(day, input);
+        assert_eq!(position(&error), expected, "day {day}: {input:?}");
+    }
+}
+
+#[test]
+fn trapped_walks_end_without_a_solution() {
+    // A guard walled in on all four sides turns on the spot forever
+    let walled = ".#.\n#^#\n.#.";
+    assert!(matches!(Day06::solve1(walled), Err(Error::NoSolution)));
+    // Blocking the only way out traps the guard too
+    assert_eq!(Day06::solve2(".#.\n#^#\n...").unwrap(), 1);
+
+    for track in ["#S.#\n###E", "S..\n###\n..E"] {
+        assert!(
+            matches!(Day20::parse(track), Err(Error::NoSolution)),
+            "{track:?}"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use adventofcode::{fixtures, registry};
+
+fn check(year: u16, day: u8) {
+    let entry = registry::find(year, day).unwrap();
+    let fixtures = fixtures::load(fixtures::DIR, year, day).unwrap();
+    assert!(!fixtures.is_empty(), "no fixtures for {year} day {day}");
+    let failures: Vec<String> = fixtures
+        .iter()
+        .flat_map(|fixture| fixture.check(entry))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+macro_rules! examples {
+    ($($module:ident: $year:literal { $($name:ident: $day:literal,)* })*) => {
+        $(
+            mod $module {
+                $(
+                    #[test]
+                    fn $name() {
+                        super::check($year, $day);
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+examples! {
+    y2024: 2024 {
+        day01: 1,
+        day02: 2,
+        day03: 3,
+        day04: 4,
+        day05: 5,
+        day06: 6,
+        day07: 7,
+        day08: 8,
+        day09: 9,
+        day10: 10,
+        day11: 11,
+        day12: 12,
+        day13: 13,
+        day14: 14,
+        day15: 15,
+        day16: 16,
+        day17: 17,
+        day18: 18,
+        day19: 19,
+        day20: 20,
+        day21: 21,
+        day22: 22,
+        day23: 23,
+        day24: 24,
+        day25: 25,
+    }
+}
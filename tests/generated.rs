@@ -0,0 +1,214 @@
+use adventofcode::{
+    generate::{self, Rng},
+    solution::{Params, Solution},
+    utils::{search, Grid, XY},
+    y2024::{day09::Day09, day14::Day14, day16::Day16, day19::Day19, day20::Day20, day24::Day24},
+};
+
+const SEEDS: std::ops::Range<u64> = 0..20;
+
+fn params(pairs: &[(&str, &str)]) -> Params {
+    let mut params = Params::default();
+    for (key, value) in pairs {
+        params.insert(*key, *value);
+    }
+    params
+}
+
+/// Steps from `S` to `E`, found with a plain breadth first search.
+fn distance(input: &str) -> usize {
+    let grid: Grid<char> = Grid::try_from(input).unwrap();
+    let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+    let neighbors = |&pos: &XY| grid.neighbors(pos).filter(|&next| grid[next] != '#');
+    search::bfs(start, neighbors, |&pos| pos == end)
+        .cost(&end)
+        .unwrap()
+}
+
+#[test]
+fn maze_without_loops_has_a_single_best_path() {
+    for seed in SEEDS {
+        let input = generate::maze(&mut Rng::new(seed), 31, 31, 0.);
+        let steps = distance(&input);
+        let cost = Day16::solve1(&input).unwrap() as usize;
+        assert_eq!(cost % 1000, steps, "seed {seed}");
+        assert_eq!(Day16::solve2(&input).unwrap(), steps + 1, "seed {seed}");
+    }
+}
+
+#[test]
+fn maze_loops_only_shorten_the_best_path() {
+    for seed in SEEDS {
+        let perfect = generate::maze(&mut Rng::new(seed), 31, 31, 0.);
+        let looped = generate::maze(&mut Rng::new(seed), 31, 31, 0.1);
+        let (looped_cost, perfect_cost) = (Day16::solve1(&looped), Day16::solve1(&perfect));
+        assert!(looped_cost.unwrap() <= perfect_cost.unwrap(), "seed {seed}");
+        assert!(distance(&looped) <= distance(&perfect), "seed {seed}");
+    }
+}
+
+/// Two step cheats counted from the grid rather than from pairs along the path.
+fn short_cheats(input: &str, min_saving: usize) -> usize {
+    let grid: Grid<char> = Grid::try_from(input).unwrap();
+    let start = grid.find(&'S').unwrap();
+    let neighbors = |&pos: &XY| grid.neighbors(pos).filter(|&next| grid[next] != '#');
+    let paths = search::bfs(start, neighbors, |_| false);
+    let mut cheats = 0;
+    for (&from, &before) in paths.dist.iter() {
+        for to in from
+            .neighbors8()
+            .into_iter()
+            .chain(from.neighbors().map(|n| n + (n - from)))
+        {
+            if from.manhattan(to) != 2 {
+                continue;
+            }
+            if let Some(&after) = paths.dist.get(&to) {
+                cheats += (after >= before + 2 + min_saving) as usize;
+            }
+        }
+    }
+    cheats
+}
+
+#[test]
+fn racetrack_cheats_agree() {
+    for seed in SEEDS {
+        let input = generate::racetrack(&mut Rng::new(seed), 31, 31);
+        let track = Day20::parse_with(&input, &params(&[("min_saving", "4")])).unwrap();
+        let short = Day20::part1(&track).unwrap();
+        assert_eq!(short, short_cheats(&input, 4), "seed {seed}");
+        assert!(Day20::part2(&track).unwrap() >= short, "seed {seed}");
+    }
+}
+
+/// Part one's block by block compaction, done with two pointers over the expanded disk.
+fn compacted_checksum(map: &str) -> usize {
+    let mut disk = Vec::new();
+    for (i, c) in map.trim().chars().enumerate() {
+        let file = (i % 2 == 0).then_some(i / 2);
+        disk.extend(std::iter::repeat_n(file, c.to_digit(10).unwrap() as usize));
+    }
+    let (mut left, mut right) = (0, disk.len() - 1);
+    while left < right {
+        match (disk[left], disk[right]) {
+            (Some(_), _) => left += 1,
+            (_, None) => right -= 1,
+            (None, Some(_)) => disk.swap(left, right),
+        }
+    }
+    disk.iter()
+        .enumerate()
+        .map(|(i, file)| i * file.unwrap_or(0))
+        .sum()
+}
+
+#[test]
+fn disk_compaction_matches_two_pointers() {
+    for seed in SEEDS {
+        let input = generate::disk_map(&mut Rng::new(seed), 200);
+        assert_eq!(
+            Day09::solve1(&input).unwrap(),
+            compacted_checksum(&input),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn robots_jump_ahead_like_they_step() {
+    for seed in SEEDS {
+        let input = generate::robots(&mut Rng::new(seed), 50, 101, 103);
+        let robots = Day14::parse(&input).unwrap();
+        let stepped = Day14::frames(&robots, &params(&[("steps", "37")]))
+            .unwrap()
+            .last();
+        let jumped = Day14::frames(&robots, &params(&[("start", "37"), ("steps", "0")]))
+            .unwrap()
+            .next();
+        assert_eq!(stepped, jumped, "seed {seed}");
+    }
+}
+
+#[test]
+fn towels_count_each_design_alone() {
+    for seed in SEEDS {
+        let input = generate::towels(&mut Rng::new(seed), 30, 20, 30);
+        let (patterns, designs) = input.split_once("\n\n").unwrap();
+        let counts: Vec<usize> = designs
+            .lines()
+            .map(|design| Day19::solve2(&format!("{patterns}\n\n{design}\n")).unwrap())
+            .collect();
+        assert!(counts.iter().step_by(2).all(|&count| count > 0));
+        let possible = counts.iter().filter(|&&count| count > 0).count();
+        assert_eq!(Day19::solve1(&input).unwrap(), possible, "seed {seed}");
+        assert_eq!(
+            Day19::solve2(&input).unwrap(),
+            counts.iter().sum(),
+            "seed {seed}"
+        );
+    }
+}
+
+fn operand(input: &str, wire: char) -> u64 {
+    input
+        .lines()
+        .filter_map(|line| line.strip_prefix(wire)?.split_once(": "))
+        .map(|(bit, value)| value.parse::<u64>().unwrap() << bit.parse::<u64>().unwrap())
+        .sum()
+}
+
+#[test]
+fn netlist_adds() {
+    for seed in 0..5 {
+        let bits = 2 + seed as usize * 4;
+        let input = generate::netlist(&mut Rng::new(seed), bits);
+        let sum = operand(&input, 'x') + operand(&input, 'y');
+        assert_eq!(Day24::solve1(&input).unwrap(), sum, "seed {seed}");
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    let inputs = |seed| {
+        let mut rng = Rng::new(seed);
+        [
+            generate::maze(&mut rng, 15, 15, 0.1),
+            generate::racetrack(&mut rng, 15, 15),
+            generate::robots(&mut rng, 10, 11, 7),
+            generate::disk_map(&mut rng, 10),
+            generate::netlist(&mut rng, 4),
+            generate::towels(&mut rng, 5, 4, 10),
+        ]
+    };
+    assert_eq!(inputs(7), inputs(7));
+    assert_ne!(inputs(7), inputs(8));
+}
+
+/// Inputs a few times the size of the real ones, to catch solvers that only scale to the puzzle.
+#[test]
+fn larger_than_real_inputs() {
+    let mut rng = Rng::new(2024);
+
+    let maze = generate::maze(&mut rng, 281, 281, 0.05);
+    assert!(Day16::solve1(&maze).unwrap() > 0);
+    assert!(Day16::solve2(&maze).unwrap() > distance(&maze));
+
+    let track = generate::racetrack(&mut rng, 201, 201);
+    let track = Day20::parse_with(&track, &params(&[("min_saving", "100")])).unwrap();
+    assert!(Day20::part2(&track).unwrap() >= Day20::part1(&track).unwrap());
+
+    let disk = generate::disk_map(&mut rng, 20_000);
+    assert_eq!(Day09::solve1(&disk).unwrap(), compacted_checksum(&disk));
+    assert!(Day09::solve2(&disk).is_ok());
+
+    let robots = generate::robots(&mut rng, 1_000, 101, 103);
+    assert!(Day14::solve1(&robots).is_ok());
+
+    let towels = generate::towels(&mut rng, 800, 800, 60);
+    assert!(Day19::solve1(&towels).unwrap() >= 400);
+
+    let netlist = generate::netlist(&mut rng, 63);
+    let sum = operand(&netlist, 'x') + operand(&netlist, 'y');
+    assert_eq!(Day24::solve1(&netlist).unwrap(), sum);
+}
@@ -0,0 +1,83 @@
+use adventofcode::{
+    error::Error,
+    utils::{xy, Direction, Grid},
+};
+
+#[test]
+fn out_of_bounds_positions_are_none() {
+    let mut grid = Grid::try_from("ab\ncd\nef").unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid.get(xy(1, 2)), Some(&'f'));
+    for outside in [xy(-1, 0), xy(0, -1), xy(2, 0), xy(0, 3), xy(-1, -1)] {
+        assert!(!grid.contains(outside), "{outside:?}");
+        assert_eq!(grid.get(outside), None, "{outside:?}");
+        assert_eq!(grid.get_mut(outside), None, "{outside:?}");
+        assert_eq!(grid.set(outside, 'z'), None, "{outside:?}");
+    }
+    // A negative x must not wrap around onto the previous row
+    assert_eq!(grid.get(xy(-1, 1)), None);
+    assert_eq!(grid.set(xy(0, 1), 'z'), Some('c'));
+    *grid.get_mut(xy(1, 1)).unwrap() = 'y';
+    assert_eq!(grid.to_string(), "ab\nzy\nef\n");
+}
+
+#[test]
+#[should_panic(expected = "outside the grid")]
+fn indexing_out_of_bounds_panics() {
+    let grid = Grid::new(3, 3, 0);
+    let _ = grid[xy(3, 0)];
+}
+
+#[test]
+fn parse_errors_point_at_the_bad_line() {
+    let ragged = Grid::<char>::try_from("abc\nab\nabc");
+    let Err(Error::Syntax(error)) = ragged else {
+        panic!("{ragged:?}");
+    };
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.text, "ab");
+    assert_eq!(error.expected, "a row of 3 cells");
+
+    let cell = Grid::<Direction>::try_from("^>\nvx");
+    let Err(Error::Syntax(error)) = cell else {
+        panic!("{cell:?}");
+    };
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.text, "x");
+    assert_eq!(error.expected, "a grid cell");
+    let named = Grid::<Direction>::parse("^>\nvx", "a direction");
+    assert!(matches!(named, Err(Error::Syntax(e)) if e.expected == "a direction"));
+
+    let empty = Grid::<char>::try_from("").unwrap();
+    assert_eq!((empty.width(), empty.height()), (0, 0));
+}
+
+#[test]
+fn find_all_reads_row_by_row() {
+    let grid = Grid::try_from("#.#\n..#\n#..").unwrap();
+    let found: Vec<_> = grid.find_all(&'#').collect();
+    assert_eq!(found, vec![xy(0, 0), xy(2, 0), xy(2, 1), xy(0, 2)]);
+    assert_eq!(grid.find(&'#'), Some(xy(0, 0)));
+    assert_eq!(grid.find(&'O'), None);
+}
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = Grid::new(3, 3, ' ');
+    let sorted = |neighbors: Vec<_>| {
+        let mut neighbors = neighbors;
+        neighbors.sort();
+        neighbors
+    };
+    let neighbors = |x, y| sorted(grid.neighbors(xy(x, y)).collect());
+    assert_eq!(neighbors(0, 0), vec![xy(0, 1), xy(1, 0)]);
+    assert_eq!(neighbors(2, 2), vec![xy(1, 2), xy(2, 1)]);
+    assert_eq!(neighbors(1, 0), vec![xy(0, 0), xy(1, 1), xy(2, 0)]);
+    assert_eq!(neighbors(0, 1), vec![xy(0, 0), xy(0, 2), xy(1, 1)]);
+    assert_eq!(neighbors(1, 1).len(), 4);
+
+    let neighbors8 = |x, y| sorted(grid.neighbors8(xy(x, y)).collect());
+    assert_eq!(neighbors8(2, 0), vec![xy(1, 0), xy(1, 1), xy(2, 1)]);
+    assert_eq!(neighbors8(1, 2).len(), 5);
+    assert_eq!(neighbors8(1, 1).len(), 8);
+}
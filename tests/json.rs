@@ -0,0 +1,120 @@
+use std::{env, fs, time::Duration};
+
+use adventofcode::{
+    bench::{Baseline, Stats, Timings},
+    json::{self, Object, Value},
+};
+
+#[test]
+fn values_round_trip() {
+    let mut object = Object::new();
+    object.insert("null".to_string(), Value::Null);
+    object.insert("yes".to_string(), Value::Boolean(true));
+    object.insert("no".to_string(), Value::Boolean(false));
+    object.insert("big".to_string(), Value::Integer(-(1 << 100)));
+    object.insert("float".to_string(), Value::Float(1.5e-3));
+    object.insert(
+        "escapes \"quoted\"".to_string(),
+        Value::String("tab\there\nnew line \\ \u{1} é ☕".to_string()),
+    );
+    object.insert(
+        "nested".to_string(),
+        Value::Array(vec![
+            Value::Array(Vec::new()),
+            Value::Object(Object::new()),
+            Value::Integer(0),
+        ]),
+    );
+    let value = Value::Object(object);
+    let written = value.to_string();
+    assert!(!written.contains('\n'), "{written}");
+    assert_eq!(json::parse(&written), Ok(value));
+}
+
+#[test]
+fn whitespace_and_escapes_are_read() {
+    let value =
+        json::parse(" { \"a\" : [ 1 , 2.5 , \"\\u00e9\\\"\" ] ,\n\t\"b\": null } ").unwrap();
+    let object = value.as_object().unwrap();
+    assert_eq!(
+        object["a"],
+        Value::Array(vec![
+            Value::Integer(1),
+            Value::Float(2.5),
+            Value::String("é\"".to_string())
+        ])
+    );
+    assert_eq!(object["b"], Value::Null);
+}
+
+#[test]
+fn malformed_documents_are_errors() {
+    for input in [
+        "",
+        "{",
+        "[1,]",
+        "[1 2]",
+        "{\"a\" 1}",
+        "{\"a\": 1,}",
+        "{a: 1}",
+        "\"unterminated",
+        "\"\\uzzzz\"",
+        "nul",
+        "1.2.3",
+        "{} {}",
+        "-",
+    ] {
+        assert!(json::parse(input).is_err(), "{input:?} parsed");
+    }
+}
+
+#[test]
+fn baselines_round_trip() {
+    let stats = |nanos: u64| Stats::new(vec![Duration::from_nanos(nanos)]);
+    let baseline = Baseline {
+        days: vec![
+            Timings {
+                year: 2024,
+                day: 1,
+                phases: vec![
+                    ("parse".to_string(), stats(10)),
+                    ("part1".to_string(), stats(20)),
+                ],
+            },
+            Timings {
+                year: 2024,
+                day: 12,
+                phases: vec![("part2".to_string(), stats(3_000_000_000))],
+            },
+            Timings {
+                year: 2025,
+                day: 1,
+                phases: vec![("parse".to_string(), stats(30))],
+            },
+        ],
+    };
+    let dir = env::temp_dir().join(format!("adventofcode-json-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("baseline.json");
+    baseline.save(&path).unwrap();
+    let loaded = Baseline::load(&path);
+    fs::write(&path, "{\"2024/day01\": {\"parse\": {\"min\": 1}}}").unwrap();
+    let incomplete = Baseline::load(&path);
+    fs::write(&path, "{\"day01\": {}}").unwrap();
+    let yearless = Baseline::load(&path);
+    fs::remove_dir_all(dir).unwrap();
+
+    let loaded = loaded.unwrap();
+    for timings in baseline.days.iter() {
+        for (phase, stats) in timings.phases.iter() {
+            assert_eq!(loaded.get(timings.year, timings.day, phase), Some(*stats));
+        }
+    }
+    // Each year's timings are only compared with the same year
+    assert_eq!(loaded.get(2024, 1, "parse"), Some(stats(10)));
+    assert_eq!(loaded.get(2025, 1, "parse"), Some(stats(30)));
+    assert_eq!(loaded.get(2025, 1, "part1"), None);
+    assert_eq!(loaded.get(2023, 12, "part2"), None);
+    assert!(incomplete.is_err());
+    assert!(yearless.is_err());
+}
@@ -0,0 +1,58 @@
+use adventofcode::utils::math::{self, Integer, Rational};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(math::gcd(12, 18), 6);
+    assert_eq!(math::gcd(-12, 18), 6);
+    assert_eq!(math::gcd(0, -7), 7);
+    assert_eq!(math::gcd(0, 0), 0);
+    assert_eq!(math::lcm(4_i64, 6), 12);
+    assert_eq!(math::lcm(-4_i64, 6), 12);
+    assert_eq!(math::lcm(0_i64, 6), 0);
+    assert_eq!(math::lcm(101_i128, 103), 10403);
+}
+
+#[test]
+fn extended_gcd_and_inverses() {
+    for (a, b) in [(240, 46), (-240, 46), (17, 5), (0, 9), (9, 0)] {
+        let (g, x, y) = math::extended_gcd(a, b);
+        assert_eq!(g, math::gcd(a, b));
+        assert_eq!(a * x + b * y, g, "{a} {b}");
+    }
+    assert_eq!(math::mod_inverse(3, 11), Some(4));
+    assert_eq!(math::mod_inverse(-3, 11), Some(7));
+    assert_eq!(math::mod_inverse(6, 9), None);
+}
+
+#[test]
+fn chinese_remainders() {
+    assert_eq!(math::crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(math::crt([(3_i64, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(math::crt([(1_i64, 4), (2, 6)]), None);
+    assert_eq!(math::crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+    // Moduli whose product only just fits
+    let (p, q) = (1_000_000_000_000_000_003_i128, 1_000_000_000_000_000_009);
+    let (x, m) = math::crt([(p - 1, p), (q - 2, q)]).unwrap();
+    assert_eq!(m, p * q);
+    assert_eq!((x % p, x % q), (p - 1, q - 2));
+}
+
+#[test]
+fn wide_products() {
+    assert_eq!(i32::MAX.mul_mod(i32::MAX, 1_000_000_007), 850_618_742);
+    let big = i128::MAX - 1;
+    assert_eq!(big.mul_mod(big, i128::MAX), 1);
+    assert_eq!((-7).modulo(3), 2);
+}
+
+#[test]
+fn exact_linear_systems() {
+    // 94a + 22b = 8400, 34a + 67b = 5400
+    let [a, b] = math::solve2([[94, 22], [34, 67]], [8400, 5400]).unwrap();
+    assert_eq!((a.integer(), b.integer()), (Some(80), Some(40)));
+    let [x, _] = math::solve2([[2, 0], [0, 1]], [1, 1]).unwrap();
+    assert_eq!(x, Rational::new(2, 4).unwrap());
+    assert_eq!(x.to_string(), "1/2");
+    assert_eq!(Rational::new(3, -6).unwrap().to_string(), "-1/2");
+    assert_eq!(math::solve2([[1, 2], [2, 4]], [1, 1]), None);
+}
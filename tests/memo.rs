@@ -0,0 +1,54 @@
+use adventofcode::utils::{memo::Stats, Memo};
+
+fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+    memo.get(n, |memo| match n {
+        0 | 1 => n,
+        _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+    })
+}
+
+#[test]
+fn recursive_calls_share_the_memo() {
+    let mut memo = Memo::new();
+    assert_eq!(memo.stats(), Stats::default());
+    assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+    // Each of 0..=90 is computed once; each n >= 3 then finds n - 2 remembered
+    let stats = Stats {
+        hits: 88,
+        misses: 91,
+        size: 91,
+    };
+    assert_eq!(memo.stats(), stats);
+
+    assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+    assert_eq!(memo.stats().hits, 89);
+    assert_eq!(memo.stats().size, 91);
+    assert_eq!(memo.stats().to_string(), "89 hits, 91 misses, 91 entries");
+}
+
+#[test]
+fn values_are_computed_once_per_key() {
+    let mut memo = Memo::new();
+    let mut calls = 0;
+    for key in ["a", "b", "a", "a", "c", "b"] {
+        let value = memo.get(key, |_| {
+            calls += 1;
+            key.len()
+        });
+        assert_eq!(value, 1);
+    }
+    assert_eq!(calls, 3);
+    assert_eq!(
+        memo.stats(),
+        Stats {
+            hits: 3,
+            misses: 3,
+            size: 3
+        }
+    );
+
+    // A fresh memo remembers nothing from the last one
+    let mut fresh: Memo<&str, usize> = Memo::new();
+    assert_eq!(fresh.get("a", |_| 2), 2);
+    assert_eq!(fresh.stats().misses, 1);
+}
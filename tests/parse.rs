@@ -0,0 +1,72 @@
+use adventofcode::utils::parse;
+
+#[test]
+fn integers_take_their_signs() {
+    let found: Vec<i64> = parse::integers("p=0,-4 v=-3,12 x-7 --2").unwrap();
+    assert_eq!(found, vec![0, -4, -3, 12, -7, -2]);
+    assert_eq!(parse::integers::<u8>("no numbers here"), Ok(Vec::new()));
+
+    let error = parse::integers::<u32>("a=1 b=-2").unwrap_err();
+    assert_eq!((error.column, error.text.as_str()), (7, "-2"));
+    assert_eq!(error.expected, "a number");
+    assert!(parse::integers::<u8>("300").is_err());
+
+    let [x, y] = parse::exactly::<i32, 2>("X+94, Y-34", "`X, Y`").unwrap();
+    assert_eq!((x, y), (94, -34));
+    let error = parse::exactly::<i32, 2>("X+94, Y-34, Z=1", "`X, Y`").unwrap_err();
+    assert_eq!(error.expected, "`X, Y`");
+    assert!(parse::exactly::<i32, 2>("X+94", "`X, Y`").is_err());
+}
+
+#[test]
+fn blocks_split_on_blank_lines() {
+    let input = "\n\na\nb\n\nc\n\n\nd\n\n\n";
+    assert_eq!(parse::blocks(input).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+
+    let crlf = "a\r\nb\r\n\r\nc\r\n\r\n";
+    let blocks: Vec<_> = parse::blocks(crlf).collect();
+    assert_eq!(blocks, ["a\r\nb", "c"]);
+    assert_eq!(blocks[0].lines().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(parse::blocks("").count(), 0);
+    assert_eq!(parse::blocks("\n\n\n").count(), 0);
+
+    assert_eq!(
+        parse::two_blocks("1|2\n\n1,2\n\n3"),
+        Ok(("1|2", "1,2\n\n3"))
+    );
+    assert_eq!(
+        parse::two_blocks("1|2\r\n\r\n1,2\r\n"),
+        Ok(("1|2", "1,2\r\n"))
+    );
+    let error = parse::two_blocks("1|2\n1,2").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+    assert_eq!(error.expected, "a blank line");
+}
+
+#[test]
+fn pairs_report_malformed_lines() {
+    assert_eq!(
+        parse::pair("x00 AND y00 -> z00", " -> "),
+        Ok(("x00 AND y00", "z00"))
+    );
+    assert_eq!(parse::pair("a-b-c", "-"), Ok(("a", "b-c")));
+    let error = parse::pair("kh tc", "-").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.text, "kh tc");
+    assert_eq!(error.expected, "`a-b`");
+
+    assert_eq!(parse::key_value("190: 10 19"), Ok(("190", "10 19")));
+    assert_eq!(parse::key_value("a: b: c"), Ok(("a", "b: c")));
+    assert_eq!(
+        parse::key_value("190:10").unwrap_err().expected,
+        "`key: value`"
+    );
+
+    assert_eq!(parse::number_pair::<u32>("47|53", "|"), Ok((47, 53)));
+    assert_eq!(parse::number_pair::<i32>("-1,2", ","), Ok((-1, 2)));
+    let error = parse::number_pair::<u32>("47|x3", "|").unwrap_err();
+    assert_eq!((error.column, error.text.as_str()), (4, "x3"));
+    assert_eq!(error.expected, "a number");
+    assert!(parse::number_pair::<u32>("47,53", "|").is_err());
+    assert!(parse::number_pair::<u32>("47|", "|").is_err());
+}
@@ -0,0 +1,146 @@
+use adventofcode::{
+    render::{self, Palette, Rgb},
+    utils::{xy, Grid},
+};
+
+/// A plain GIF LZW decoder, written from the format rather than from the encoder.
+fn decode(data: &[u8], min_size: u32) -> Vec<u8> {
+    let clear = 1 << min_size;
+    let end = clear + 1;
+    let initial: Vec<Vec<u8>> = (0..=end).map(|i| vec![i as u8]).collect();
+    let mut table = initial.clone();
+    let mut size = min_size + 1;
+    let mut previous: Option<Vec<u8>> = None;
+    let mut out = Vec::new();
+    let (mut buffer, mut length) = (0u32, 0u32);
+    let mut bytes = data.iter();
+    loop {
+        while length < size {
+            buffer |= (*bytes.next().expect("no end code") as u32) << length;
+            length += 8;
+        }
+        let code = (buffer & ((1 << size) - 1)) as usize;
+        buffer >>= size;
+        length -= size;
+        if code == clear {
+            table = initial.clone();
+            size = min_size + 1;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            return out;
+        }
+        let entry = match (table.get(code), &previous) {
+            (Some(entry), _) => entry.clone(),
+            (None, Some(previous)) if code == table.len() => {
+                let mut entry = previous.clone();
+                entry.push(previous[0]);
+                entry
+            }
+            _ => panic!("code {code} is not in the table"),
+        };
+        if let Some(mut previous) = previous.take() {
+            if table.len() < 4096 {
+                previous.push(entry[0]);
+                table.push(previous);
+            }
+        }
+        if table.len() == 1 << size && size < 12 {
+            size += 1;
+        }
+        out.extend_from_slice(&entry);
+        previous = Some(entry);
+    }
+}
+
+/// The global colour table and the pixels of every frame in a GIF written by [`render::gif`].
+fn read_gif(gif: &[u8]) -> (Vec<Rgb>, Vec<Vec<u8>>) {
+    assert_eq!(&gif[..6], b"GIF89a");
+    let depth = (gif[10] & 0x07) as u32 + 1;
+    let table_end = 13 + 3 * (1 << depth);
+    let colors = gif[13..table_end]
+        .chunks(3)
+        .map(|c| [c[0], c[1], c[2]])
+        .collect();
+    let mut at = table_end + 19;
+    let mut frames = Vec::new();
+    while gif[at] != 0x3b {
+        assert_eq!(&gif[at..at + 3], &[0x21, 0xf9, 0x04]);
+        at += 8;
+        assert_eq!(gif[at], 0x2c);
+        at += 10;
+        let min_size = gif[at] as u32;
+        at += 1;
+        let mut data = Vec::new();
+        while gif[at] != 0 {
+            let length = gif[at] as usize;
+            data.extend_from_slice(&gif[at + 1..at + 1 + length]);
+            at += 1 + length;
+        }
+        at += 1;
+        frames.push(decode(&data, min_size));
+    }
+    assert_eq!(at + 1, gif.len());
+    (colors, frames)
+}
+
+fn palette() -> Palette {
+    let mut palette = Palette::new([9, 9, 9]);
+    palette.set('#', [255, 0, 0]);
+    palette.set('.', [0, 0, 255]);
+    palette.set('O', [0, 255, 0]);
+    palette
+}
+
+#[test]
+fn small_frame_decodes_to_its_colour_indices() {
+    let mut palette = Palette::new([9, 9, 9]);
+    palette.set('#', [255, 0, 0]);
+    palette.set('.', [0, 0, 255]);
+    let frame = Grid::try_from("#.#\n.#.").unwrap();
+    let mut gif = Vec::new();
+    assert_eq!(render::gif(&mut gif, [frame], &palette, 1, 5).unwrap(), 1);
+    let (colors, frames) = read_gif(&gif);
+    assert_eq!(colors, vec![[9, 9, 9], [255, 0, 0], [0, 0, 255], [0, 0, 0]]);
+    assert_eq!(frames, vec![vec![1, 2, 1, 2, 1, 2]]);
+}
+
+#[test]
+fn large_frames_survive_table_resets() {
+    // Enough varied pixels to fill the 4096 entry code table several times over
+    let mut state = 12345_u32;
+    let mut random = || {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        ['#', '.', 'O', ' '][(state >> 16) as usize % 4]
+    };
+    let frames: Vec<Grid<char>> = (0..2)
+        .map(|_| {
+            let mut grid = Grid::new(120, 80, ' ');
+            for y in 0..80 {
+                for x in 0..120 {
+                    grid.set(xy(x, y), random());
+                }
+            }
+            grid
+        })
+        .collect();
+    let palette = palette();
+    let mut gif = Vec::new();
+    render::gif(&mut gif, frames.clone(), &palette, 2, 5).unwrap();
+    let (colors, decoded) = read_gif(&gif);
+    assert_eq!(decoded.len(), frames.len());
+    for (frame, pixels) in frames.iter().zip(decoded) {
+        let expected: Vec<Rgb> = frame
+            .rows()
+            .flat_map(|row| {
+                let line: Vec<Rgb> = row.iter().flat_map(|&c| [palette.color(c); 2]).collect();
+                [line.clone(), line]
+            })
+            .flatten()
+            .collect();
+        let found: Vec<Rgb> = pixels.iter().map(|&i| colors[i as usize]).collect();
+        assert_eq!(found.len(), expected.len());
+        assert!(found == expected, "decoded pixels differ");
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, fs, path::Path};
+
+use adventofcode::{error::Error, scaffold};
+
+const LIB: &str = "pub mod registry;\npub mod y2024;\n";
+
+const YEAR: &str = "pub mod day01;\npub mod day05;\n";
+
+const REGISTRY: &str = "\
+pub static DAYS: &[Day] = &[
+    day!(2024, 1, y2024::day01::Day01),
+    day!(2024, 5, y2024::day05::Day05),
+];
+";
+
+const EXAMPLES: &str = "\
+examples! {
+    y2024: 2024 {
+        day01: 1,
+        day05: 5,
+    }
+}
+";
+
+fn tree(name: &str) -> std::path::PathBuf {
+    let root = env::temp_dir().join(format!("adventofcode-{name}-{}", std::process::id()));
+    for (path, contents) in [
+        ("src/lib.rs", LIB),
+        ("src/y2024/mod.rs", YEAR),
+        ("src/registry.rs", REGISTRY),
+        ("tests/examples.rs", EXAMPLES),
+    ] {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    root
+}
+
+fn read(root: &Path, path: &str) -> String {
+    fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn scaffolds_a_day_in_an_existing_year() {
+    let root = tree("scaffold-day");
+    let written = scaffold::create(&root, 2024, 3).unwrap();
+    let (module, year, registry, examples) = (
+        read(&root, "src/y2024/day03/mod.rs"),
+        read(&root, "src/y2024/mod.rs"),
+        read(&root, "src/registry.rs"),
+        read(&root, "tests/examples.rs"),
+    );
+    let fixture = root.join("fixtures/2024/day03");
+    let fixtures = (fixture.join("example.txt"), fixture.join("example.toml"));
+    let lib = read(&root, "src/lib.rs");
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(module.contains("pub struct Day03;"));
+    assert!(module.contains("#[aocd(2024, 3)]"));
+    assert!(written.contains(&fixtures.0) && written.contains(&fixtures.1));
+    assert_eq!(year, "pub mod day01;\npub mod day03;\npub mod day05;\n");
+    assert!(registry.contains(
+        "    day!(2024, 1, y2024::day01::Day01),\n\
+         \x20   day!(2024, 3, y2024::day03::Day03),\n\
+         \x20   day!(2024, 5, y2024::day05::Day05),\n"
+    ));
+    assert!(examples.contains("        day01: 1,\n        day03: 3,\n        day05: 5,\n"));
+    assert_eq!(lib, LIB);
+}
+
+#[test]
+fn scaffolds_a_new_year() {
+    let root = tree("scaffold-year");
+    scaffold::create(&root, 2025, 1).unwrap();
+    let (lib, year, registry, examples) = (
+        read(&root, "src/lib.rs"),
+        read(&root, "src/y2025/mod.rs"),
+        read(&root, "src/registry.rs"),
+        read(&root, "tests/examples.rs"),
+    );
+    fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(lib, "pub mod registry;\npub mod y2024;\npub mod y2025;\n");
+    assert_eq!(year, "pub mod day01;\n");
+    assert!(registry.contains(
+        "    day!(2024, 5, y2024::day05::Day05),\n\
+         \x20   day!(2025, 1, y2025::day01::Day01),\n];"
+    ));
+    assert!(examples
+        .contains("        day05: 5,\n    }\n    y2025: 2025 {\n        day01: 1,\n    }\n}"));
+}
+
+#[test]
+fn refuses_to_overwrite_a_day() {
+    let root = tree("scaffold-twice");
+    scaffold::create(&root, 2024, 3).unwrap();
+    let module = root.join("src/y2024/day03/mod.rs");
+    fs::write(&module, "// solved").unwrap();
+    let again = scaffold::create(&root, 2024, 3);
+
+    // A day whose fixtures exist is refused too, even without its module
+    fs::create_dir_all(root.join("fixtures/2024/day07")).unwrap();
+    let fixtures_only = scaffold::create(&root, 2024, 7);
+    let (contents, registry) = (
+        fs::read_to_string(&module).unwrap(),
+        read(&root, "src/registry.rs"),
+    );
+    let module_for_7 = root.join("src/y2024/day07").exists();
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(matches!(again, Err(Error::Input(message)) if message.contains("already exists")));
+    assert!(fixtures_only.is_err());
+    assert_eq!(contents, "// solved");
+    assert_eq!(registry.matches("day!(2024, 3,").count(), 1);
+    assert!(!module_for_7);
+}
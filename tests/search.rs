@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use adventofcode::utils::{search, xy, Grid, XY};
+
+/// Two equally cheap routes from `a` to `d`, a dearer direct edge, and `e` beyond `d`.
+fn diamond(node: &char) -> Vec<(char, u32)> {
+    match node {
+        'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+        'b' => vec![('d', 2)],
+        'c' => vec![('d', 1)],
+        'd' => vec![('e', 1)],
+        _ => Vec::new(),
+    }
+}
+
+const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+fn open_neighbors(grid: &Grid<char>) -> impl FnMut(&XY) -> Vec<XY> + '_ {
+    |&pos| {
+        grid.neighbors(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+}
+
+#[test]
+fn dijkstra_keeps_every_cheapest_predecessor() {
+    let paths = search::dijkstra('a', diamond, |&node| node == 'e');
+    assert_eq!(paths.cost(&'e'), Some(4));
+    assert_eq!(paths.cost(&'d'), Some(3));
+    assert_eq!(paths.cost(&'z'), None);
+    assert_eq!(paths.prev[&'d'], vec!['b', 'c']);
+
+    let path = paths.path(&'e').unwrap();
+    assert!(
+        path == ['a', 'b', 'd', 'e'] || path == ['a', 'c', 'd', 'e'],
+        "{path:?}"
+    );
+    let mut all = paths.all(&'e');
+    all.sort();
+    assert_eq!(
+        all,
+        vec![vec!['a', 'b', 'd', 'e'], vec!['a', 'c', 'd', 'e']]
+    );
+    assert_eq!(paths.path(&'a'), Some(vec!['a']));
+    assert_eq!(paths.all(&'z'), Vec::<Vec<char>>::new());
+
+    assert_eq!(paths.nodes(['e']), HashSet::from(['a', 'b', 'c', 'd', 'e']));
+    // Only the cheapest of several goals counts
+    assert_eq!(paths.nodes(['e', 'b']), HashSet::from(['a', 'b']));
+    assert_eq!(paths.nodes(['z']), HashSet::new());
+}
+
+#[test]
+fn dijkstra_does_not_expand_goals() {
+    let paths = search::dijkstra('a', diamond, |&node| node == 'd');
+    assert_eq!(paths.cost(&'d'), Some(3));
+    assert_eq!(paths.cost(&'e'), None);
+}
+
+#[test]
+fn bfs_counts_steps() {
+    let grid = Grid::try_from(MAZE).unwrap();
+    let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+    let paths = search::bfs(start, open_neighbors(&grid), |&pos| pos == end);
+    assert_eq!(paths.cost(&end), Some(15));
+    let path = paths.path(&end).unwrap();
+    assert_eq!(path.len(), 16);
+    assert_eq!((path[0], path[15]), (start, end));
+    assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+    assert!(path.iter().all(|&pos| grid[pos] != '#'));
+
+    let walled = search::bfs(xy(0, 0), |_| Vec::new(), |&pos| pos == end);
+    assert_eq!(walled.path(&end), None);
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    let grid = Grid::try_from(MAZE).unwrap();
+    let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+    let mut neighbors = open_neighbors(&grid);
+    let (path, cost) = search::astar(
+        start,
+        |pos| neighbors(pos).into_iter().map(|next| (next, 1)),
+        |pos| pos.manhattan(end),
+        |&pos| pos == end,
+    )
+    .unwrap();
+    assert_eq!(cost, 15);
+    assert_eq!(path.len(), 16);
+    assert_eq!((path[0], path[15]), (start, end));
+
+    let found = search::astar('a', diamond, |_| 0, |&node| node == 'e');
+    assert_eq!(found.map(|(path, cost)| (path.len(), cost)), Some((4, 4)));
+    assert_eq!(
+        search::astar('a', diamond, |_| 0, |&node| node == 'z'),
+        None
+    );
+}
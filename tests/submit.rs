@@ -0,0 +1,137 @@
+use std::{env, fs, time::Duration};
+
+use adventofcode::{
+    answer::Answer,
+    history::{Attempt, History},
+    input::{Cache, InputSource},
+    mock::{MockServer, Site},
+    registry,
+    solution::Params,
+    submit::{self, Http, Submitter, Verdict},
+};
+
+const INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+fn server(cooldown: Duration) -> MockServer {
+    let mut site = Site {
+        cooldown,
+        ..Site::default()
+    };
+    site.inputs.insert((2024, 1), INPUT.to_string());
+    site.answers.insert((2024, 1, 1), Answer::from(11_u64));
+    site.answers.insert((2024, 1, 2), Answer::from(31_u64));
+    MockServer::start(site, "127.0.0.1:0").unwrap()
+}
+
+#[test]
+fn fetch_solve_and_submit() {
+    let server = server(Duration::ZERO);
+    let dir = env::temp_dir().join(format!("adventofcode-submit-{}", std::process::id()));
+    let cache = Cache::new(&dir, Some(Box::new(Http::new(server.url()))));
+    let input = cache.read(2024, 1).unwrap();
+    assert_eq!(input, INPUT);
+    assert!(cache.path(2024, 1).exists());
+
+    let entry = registry::find(2024, 1).unwrap();
+    let parsed = (entry.parse)(&input, &Params::default()).unwrap();
+    let site = Http::new(server.url());
+    let (part1, part2) = (parsed.part(1).unwrap(), parsed.part(2).unwrap());
+    assert_eq!(site.submit(2024, 1, 2, &part2), Ok(Verdict::WrongLevel));
+    assert_eq!(site.submit(2024, 1, 1, &part1), Ok(Verdict::Correct));
+    assert_eq!(site.submit(2024, 1, 2, &part2), Ok(Verdict::Correct));
+    assert_eq!(site.submit(2024, 1, 1, &part1), Ok(Verdict::WrongLevel));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn wrong_answers_say_which_way() {
+    let server = server(Duration::ZERO);
+    let site = Http::new(server.url());
+    assert_eq!(site.submit(2024, 1, 1, &10.into()), Ok(Verdict::TooLow));
+    assert_eq!(site.submit(2024, 1, 1, &12.into()), Ok(Verdict::TooHigh));
+    assert_eq!(
+        site.submit(2024, 1, 1, &"eleven".into()),
+        Ok(Verdict::Wrong)
+    );
+}
+
+#[test]
+fn wrong_answers_lock_out_submissions() {
+    let server = server(Duration::from_secs(60));
+    let site = Http::new(server.url());
+    assert_eq!(site.submit(2024, 1, 1, &10.into()), Ok(Verdict::TooLow));
+    assert_eq!(
+        site.submit(2024, 1, 1, &11.into()),
+        Ok(Verdict::RateLimited(Some(Duration::from_secs(60))))
+    );
+}
+
+#[test]
+fn verdicts_from_site_pages() {
+    let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+    let cases = [
+        (
+            "That's the right answer!  You are one gold star closer.",
+            Verdict::Correct,
+        ),
+        (
+            "That's not the right answer; your answer is too high.  If you're stuck, ...",
+            Verdict::TooHigh,
+        ),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 2s left to wait. [Return to Day 1]",
+            Verdict::RateLimited(Some(Duration::from_secs(62))),
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it?",
+            Verdict::WrongLevel,
+        ),
+    ];
+    for (message, expected) in cases {
+        assert_eq!(submit::verdict(&page(message)), Ok(expected), "{message}");
+    }
+    assert!(submit::verdict("<html></html>").is_err());
+}
+
+#[test]
+fn history_bounds_and_refusals() {
+    let attempt = |part, answer: Answer, verdict| Attempt {
+        day: 1,
+        part,
+        answer,
+        verdict,
+        time: 1733011200,
+    };
+    let dir = env::temp_dir().join(format!("adventofcode-history-{}", std::process::id()));
+    let path = History::path(&dir, 2024);
+    let mut history = History::load(&path).unwrap();
+    assert!(history.attempts.is_empty());
+    for (part, answer, verdict) in [
+        (1, 5.into(), Verdict::TooLow),
+        (1, 20.into(), Verdict::TooHigh),
+        (1, 8.into(), Verdict::TooLow),
+        (1, "eight".into(), Verdict::Wrong),
+        (2, 31.into(), Verdict::Correct),
+    ] {
+        history
+            .append(&path, attempt(part, answer, verdict))
+            .unwrap();
+    }
+    assert_eq!(History::load(&path).unwrap(), history);
+    fs::remove_dir_all(dir).unwrap();
+
+    assert_eq!(history.bounds(1, 1), (Some(8), Some(20)));
+    assert_eq!(history.bounds(1, 2), (None, None));
+    assert_eq!(history.refusal(1, 1, &11.into()), None);
+    assert!(history.refusal(1, 1, &8.into()).is_some());
+    assert!(history.refusal(1, 1, &7.into()).is_some());
+    assert!(history.refusal(1, 1, &20_u64.into()).is_some());
+    assert!(history.refusal(1, 1, &"eight".into()).is_some());
+    assert_eq!(history.refusal(1, 1, &"nine".into()), None);
+    assert!(history.refusal(1, 2, &31.into()).is_some());
+    assert_eq!(
+        adventofcode::history::timestamp(1733011200),
+        "2024-12-01 00:00:00"
+    );
+}
@@ -0,0 +1,100 @@
+use std::io::Cursor;
+
+use adventofcode::{
+    render::Frames,
+    utils::Grid,
+    viewer::{self, Recording},
+};
+
+/// `count` one-cell frames showing their own index.
+fn frames(count: u32) -> Frames {
+    Box::new((0..count).map(|i| Grid::new(1, 1, char::from_digit(i, 10).unwrap())))
+}
+
+/// Each screen drawn, as its frame and then its status line.
+fn screens(commands: &str, count: u32, first: usize, start: usize) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    viewer::view(
+        frames(count),
+        first,
+        start,
+        &mut Cursor::new(commands),
+        &mut out,
+    )
+    .unwrap();
+    String::from_utf8(out)
+        .unwrap()
+        .split("\x1b[2J\x1b[H")
+        .skip(1)
+        .map(|screen| {
+            let lines: Vec<&str> = screen.lines().collect();
+            (lines[0].to_string(), lines[1].to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn commands_move_between_frames() {
+    let commands = "n\np\np\ng 4\n2\n\nn 10\nx\np 2\nq\nn\n";
+    let status: Vec<String> = screens(commands, 5, 1, 1)
+        .into_iter()
+        .map(|(frame, status)| format!("{frame} {status}"))
+        .collect();
+    assert_eq!(
+        status,
+        [
+            "0 step 1",
+            "1 step 2",
+            "0 step 1",
+            // Going back from the first frame stays there
+            "0 step 1",
+            "3 step 4",
+            "1 step 2",
+            "2 step 3",
+            // Running past the end stops at the last frame and learns the total
+            "4 step 5 of 5",
+            "4 step 5 of 5 (unknown command)",
+            "2 step 3 of 5",
+        ]
+    );
+}
+
+#[test]
+fn steps_are_numbered_from_first() {
+    // Numbered from zero and starting past the end
+    let status: Vec<String> = screens("g 0\n9\n", 3, 0, 7)
+        .into_iter()
+        .map(|(_, status)| status)
+        .collect();
+    assert_eq!(status, ["step 2 of 2", "step 0 of 2", "step 2 of 2"]);
+
+    // Jumping before the first step goes to it, and the end of the input quits
+    let status: Vec<String> = screens("g 3", 3, 10, 11)
+        .into_iter()
+        .map(|(frame, status)| format!("{frame} {status}"))
+        .collect();
+    assert_eq!(status, ["1 step 11", "0 step 10"]);
+}
+
+#[test]
+fn nothing_to_view() {
+    let mut out = Vec::new();
+    viewer::view(frames(0), 1, 1, &mut Cursor::new("n\n"), &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "no frames to view\n");
+}
+
+#[test]
+fn recordings_keep_frames_for_revisiting() {
+    let mut pulled = 0;
+    let counted: Frames = Box::new(frames(4).inspect(move |_| {
+        pulled += 1;
+        assert!(pulled <= 4, "frame pulled twice");
+    }));
+    let mut recording = Recording::new(counted);
+    assert_eq!(recording.get(1), Some(&Grid::new(1, 1, '1')));
+    assert_eq!(recording.total(), None);
+    assert_eq!(recording.get(0), Some(&Grid::new(1, 1, '0')));
+    assert_eq!(recording.get(4), None);
+    assert_eq!(recording.total(), Some(4));
+    assert_eq!(recording.get(3), Some(&Grid::new(1, 1, '3')));
+}